@@ -0,0 +1,94 @@
+use std::fmt::{self, Display};
+
+/// Malformed puzzle input, with the location of the offending text.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based byte column.
+    pub column: usize,
+    pub text: String,
+    pub msg: String,
+}
+
+impl ParseError {
+    /// Error at byte `offset` of `input`, the text is the rest of that line.
+    pub fn at(input: &[u8], offset: usize, msg: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        Self::span(input, offset, &input[offset..], msg.into())
+    }
+
+    /// Error at `part`, which has to be a subslice of `input`. The text stops at
+    /// the end of the line.
+    ///
+    /// # Panics
+    /// When `part` does not point into `input`.
+    pub fn at_slice(input: &[u8], part: &[u8], msg: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|o| o + part.len() <= input.len())
+            .expect("part is not a subslice of input");
+        if part.is_empty() {
+            Self::at(input, offset, msg)
+        } else {
+            Self::span(input, offset, part, msg.into())
+        }
+    }
+
+    fn span(input: &[u8], offset: usize, text: &[u8], msg: String) -> Self {
+        let text = text.split(|&c| c == b'\n').next().unwrap_or_default();
+        let before = &input[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |p| p + 1);
+        Self {
+            line: before.iter().filter(|&&c| c == b'\n').count() + 1,
+            column: offset - line_start + 1,
+            text: String::from_utf8_lossy(text)
+                .trim_end_matches('\r')
+                .to_owned(),
+            msg,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.msg)?;
+        if self.text.is_empty() {
+            write!(f, " at end of line")
+        } else {
+            write!(f, ": `{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    const INPUT: &[u8] = b"1-2,3-4\n5-x,7-8\n";
+
+    #[test]
+    fn test_at() {
+        let e = ParseError::at(INPUT, 10, "expected a number");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x,7-8"));
+        assert_eq!(e.to_string(), "2:3: expected a number: `x,7-8`");
+
+        let e = ParseError::at(INPUT, 0, "bad");
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+
+    #[test]
+    fn test_at_slice() {
+        let e = ParseError::at_slice(INPUT, &INPUT[10..11], "expected a number");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+
+        let e = ParseError::at_slice(INPUT, &INPUT[16..16], "missing pair");
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.to_string(), "3:1: missing pair at end of line");
+    }
+}
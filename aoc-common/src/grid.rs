@@ -0,0 +1,296 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{lines, Loc, ParseError};
+
+/// Row-major 2D map, `(0, 0)` is the top left.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![fill; width * height],
+        }
+    }
+
+    /// Adds `rows` rows of `fill` below the last one.
+    pub fn push_rows(&mut self, rows: usize, fill: T) {
+        self.data.resize(self.data.len() + rows * self.width, fill);
+        self.height += rows;
+    }
+}
+
+impl<T> Grid<T> {
+    /// # Panics
+    /// When `data` is not a whole number of rows.
+    pub fn from_vec(width: usize, data: Vec<T>) -> Self {
+        assert!(
+            data.len().is_multiple_of(width),
+            "{} cells do not fit in rows of {width}",
+            data.len()
+        );
+        Self {
+            width,
+            height: data.len().checked_div(width).unwrap_or(0),
+            data,
+        }
+    }
+
+    /// One row per line, `cell` turns a byte into a tile or rejects it with
+    /// `expected` as message.
+    ///
+    /// # Errors
+    /// When the rows differ in length or `cell` rejects a byte.
+    pub fn parse(
+        input: &[u8],
+        cell: impl Fn(u8) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut data = Vec::with_capacity(input.len());
+
+        for line in lines(input) {
+            if *width.get_or_insert(line.len()) != line.len() {
+                return Err(ParseError::at_slice(input, line, "rows differ in length"));
+            }
+            for (x, &c) in line.iter().enumerate() {
+                let tile =
+                    cell(c).ok_or_else(|| ParseError::at_slice(input, &line[x..=x], expected))?;
+                data.push(tile);
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or(0), data))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Flat index of `loc`, for the days that keep plain indices around.
+    pub fn idx(&self, loc: Loc<usize>) -> usize {
+        loc.y * self.width + loc.x
+    }
+
+    pub fn loc(&self, idx: usize) -> Loc<usize> {
+        Loc::new(idx % self.width, idx / self.width)
+    }
+
+    pub fn contains(&self, loc: Loc<usize>) -> bool {
+        loc.x < self.width && loc.y < self.height
+    }
+
+    pub fn get(&self, loc: Loc<usize>) -> Option<&T> {
+        self.contains(loc).then(|| &self.data[self.idx(loc)])
+    }
+
+    pub fn get_mut(&mut self, loc: Loc<usize>) -> Option<&mut T> {
+        if self.contains(loc) {
+            let idx = self.idx(loc);
+            Some(&mut self.data[idx])
+        } else {
+            None
+        }
+    }
+
+    /// `(x, y)` wrapped around the edges, like on a torus.
+    ///
+    /// # Panics
+    /// When the grid is empty.
+    pub fn wrap(&self, x: isize, y: isize) -> Loc<usize> {
+        let wrap = |v: isize, len: usize| {
+            let len = isize::try_from(len).expect("grid too large");
+            v.rem_euclid(len).unsigned_abs()
+        };
+        Loc::new(wrap(x, self.width), wrap(y, self.height))
+    }
+
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    /// # Panics
+    /// When `x` is not a column of the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} outside width {}", self.width);
+        self.data[x..].iter().step_by(self.width.max(1))
+    }
+
+    /// Up, right, down and left of `loc`, as far as they are on the grid.
+    pub fn neighbours4(&self, loc: Loc<usize>) -> impl Iterator<Item = Loc<usize>> + '_ {
+        self.offsets(loc, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// [`Grid::neighbours4`] and the diagonals, clockwise from the top left.
+    pub fn neighbours8(&self, loc: Loc<usize>) -> impl Iterator<Item = Loc<usize>> + '_ {
+        self.offsets(
+            loc,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+            ],
+        )
+    }
+
+    fn offsets(
+        &self,
+        loc: Loc<usize>,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Loc<usize>> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let next = Loc::new(loc.x.checked_add_signed(dx)?, loc.y.checked_add_signed(dy)?);
+            self.contains(next).then_some(next)
+        })
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.data[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.data[idx]
+    }
+}
+
+impl<T> Index<Loc<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, loc: Loc<usize>) -> &T {
+        self.get(loc)
+            .unwrap_or_else(|| panic!("{loc} outside {}x{}", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Loc<usize>> for Grid<T> {
+    fn index_mut(&mut self, loc: Loc<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(loc)
+            .unwrap_or_else(|| panic!("{loc} outside {width}x{height}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::Loc;
+
+    const INPUT: &[u8] = b"123\n456\r\n789\n";
+
+    fn digits(input: &[u8]) -> Grid<u8> {
+        Grid::parse(
+            input,
+            |c| c.is_ascii_digit().then(|| c - b'0'),
+            "expected a digit",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits(INPUT);
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 3, 9));
+        assert_eq!(grid[Loc::new(2, 1)], 6);
+        assert_eq!(grid[grid.idx(Loc::new(0, 2))], 7);
+        assert_eq!(grid.loc(5), Loc::new(2, 1));
+        assert_eq!(digits(b""), Grid::from_vec(0, vec![]));
+
+        let e = Grid::parse(
+            b"12\n3x",
+            |c| c.is_ascii_digit().then_some(c),
+            "expected a digit",
+        )
+        .unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+        let e = Grid::parse(b"12\n345", Some, "").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (2, "rows differ in length"));
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = digits(INPUT);
+        assert_eq!(grid.get(Loc::new(3, 0)), None);
+        assert_eq!(grid.get(Loc::new(0, 3)), None);
+        *grid.get_mut(Loc::new(1, 1)).unwrap() = 0;
+        grid.row_mut(2)[0] = 0;
+
+        assert_eq!(grid.row(1), [4, 0, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 0, 8]);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(*grid.get_wrapping(-1, 3), 3);
+        assert_eq!(grid.wrap(7, -4), Loc::new(1, 2));
+
+        grid.push_rows(2, 9);
+        assert_eq!((grid.height(), grid.len()), (5, 15));
+        assert_eq!(grid.row(2), [0, 8, 9]);
+        assert_eq!(grid.row(4), [9, 9, 9]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(
+            grid.neighbours4(Loc::new(0, 0)).collect::<Vec<_>>(),
+            [Loc::new(1, 0), Loc::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(Loc::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Loc::new(1, 0)).count(), 5);
+        assert_eq!(
+            grid.neighbours8(Loc::new(2, 1)).collect::<Vec<_>>(),
+            [Loc::new(1, 0), Loc::new(2, 0), Loc::new(1, 1)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_outside() {
+        let _ = Grid::new(2, 2, 0)[Loc::new(2, 0)];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_outside() {
+        let _ = Grid::new(2, 2, 0).column(2);
+    }
+}
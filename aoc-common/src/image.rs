@@ -0,0 +1,352 @@
+use std::{borrow::Cow, io, time::Duration};
+
+use crate::{render::Cell, Color, Frame, Grid, Loc};
+
+/// Red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// Colour per cell value, for the values the puzzle uses like `pos::WALL`.
+#[derive(Debug, Clone)]
+pub struct Palette<T> {
+    colors: Vec<(T, Rgb)>,
+    other: Rgb,
+}
+
+impl<T: PartialEq> Palette<T> {
+    /// Every value gets `other` until it is given a colour.
+    pub fn new(other: Rgb) -> Self {
+        Self {
+            colors: Vec::new(),
+            other,
+        }
+    }
+
+    pub fn with(mut self, value: T, color: Rgb) -> Self {
+        self.colors.push((value, color));
+        self
+    }
+
+    pub fn color(&self, value: &T) -> Rgb {
+        self.colors
+            .iter()
+            .find(|(v, _)| v == value)
+            .map_or(self.other, |&(_, color)| color)
+    }
+}
+
+impl Palette<char> {
+    /// For [`Frame`]s: dark background, light marks.
+    pub fn frame() -> Self {
+        Self::new([230, 230, 230])
+            .with('.', [20, 20, 40])
+            .with(' ', [20, 20, 40])
+            .with('#', [150, 150, 150])
+    }
+}
+
+/// Black through red and yellow to white as `value` goes from 0 to `max`.
+pub fn heat(value: usize, max: usize) -> Rgb {
+    let t = value.min(max) as f64 / max.max(1) as f64;
+    let level = |from: f64| ((t * 3.0 - from).clamp(0.0, 1.0) * 255.0).round() as u8;
+    [level(0.0), level(1.0), level(2.0)]
+}
+
+/// Colour of the terminal 256 colour palette.
+fn xterm(color: Color) -> Rgb {
+    const BASIC: [Rgb; 16] = [
+        [0, 0, 0],
+        [205, 0, 0],
+        [0, 205, 0],
+        [205, 205, 0],
+        [0, 0, 238],
+        [205, 0, 205],
+        [0, 205, 205],
+        [229, 229, 229],
+        [127, 127, 127],
+        [255, 0, 0],
+        [0, 255, 0],
+        [255, 255, 0],
+        [92, 92, 255],
+        [255, 0, 255],
+        [0, 255, 255],
+        [255, 255, 255],
+    ];
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match color {
+        0..=15 => BASIC[usize::from(color)],
+        16..=231 => {
+            let c = usize::from(color - 16);
+            [LEVELS[c / 36], LEVELS[c / 6 % 6], LEVELS[c % 6]]
+        }
+        _ => [8 + 10 * (color - 232); 3],
+    }
+}
+
+/// Picture with one pixel per cell, see [`Image::scaled`] for bigger ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            pixels: Grid::new(width, height, fill),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        let pixels = (0..grid.len()).map(|idx| color(&grid[idx])).collect();
+        Self {
+            pixels: Grid::from_vec(grid.width(), pixels),
+        }
+    }
+
+    /// The part of `frame` that fits in `size` cells. Painted cells keep their
+    /// terminal colour, the others get theirs from `palette`.
+    pub fn from_frame(frame: &Frame, palette: &Palette<char>, size: Loc<usize>) -> Self {
+        let view = frame.view(size);
+        Self::from_grid(&view, |&Cell { ch, color }| {
+            color.map_or_else(|| palette.color(&ch), xterm)
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        self.pixels.get(Loc::new(x, y)).copied()
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if let Some(p) = self.pixels.get_mut(Loc::new(x, y)) {
+            *p = color;
+        }
+    }
+
+    /// Every pixel becomes a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let (width, height) = (self.width() * factor, self.height() * factor);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in self.pixels.rows() {
+            let line: Vec<Rgb> = row
+                .iter()
+                .flat_map(|&p| std::iter::repeat_n(p, factor))
+                .collect();
+            for _ in 0..factor {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Self {
+            pixels: Grid::from_vec(width, pixels),
+        }
+    }
+
+    /// Smallest part that holds every pixel that is not `background`.
+    pub fn trimmed(&self, background: Rgb) -> Self {
+        let mut min = Loc::new(usize::MAX, usize::MAX);
+        let mut max = Loc::new(0, 0);
+        for idx in 0..self.pixels.len() {
+            if self.pixels[idx] != background {
+                let loc = self.pixels.loc(idx);
+                min = Loc::new(min.x.min(loc.x), min.y.min(loc.y));
+                max = Loc::new(max.x.max(loc.x), max.y.max(loc.y));
+            }
+        }
+        if min.x > max.x {
+            return Self::new(0, 0, background);
+        }
+        self.cropped(min, Loc::new(max.x - min.x + 1, max.y - min.y + 1))
+    }
+
+    /// The `size` pixels from `top_left` on, as far as they are in the image.
+    pub fn cropped(&self, top_left: Loc<usize>, size: Loc<usize>) -> Self {
+        let x = top_left.x.min(self.width())..(top_left.x + size.x).min(self.width());
+        let y = top_left.y.min(self.height())..(top_left.y + size.y).min(self.height());
+        let width = x.len();
+        let pixels = y
+            .flat_map(|y| self.pixels.row(y)[x.clone()].iter().copied())
+            .collect();
+        Self {
+            pixels: Grid::from_vec(width, pixels),
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        (0..self.pixels.len())
+            .flat_map(|idx| self.pixels[idx])
+            .collect()
+    }
+
+    /// # Errors
+    /// When writing to `out` fails or the image is larger than PNG allows.
+    pub fn write_png(&self, out: impl io::Write) -> io::Result<()> {
+        let size = |v: usize| u32::try_from(v).map_err(io::Error::other);
+        let mut encoder = png::Encoder::new(out, size(self.width())?, size(self.height())?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.bytes()))
+            .map_err(io::Error::other)
+    }
+}
+
+/// Animated GIF, one [`Image`] per frame.
+pub struct GifWriter<W: io::Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    background: Rgb,
+    /// In hundredths of a second.
+    delay: u16,
+}
+
+impl<W: io::Write> GifWriter<W> {
+    /// Frames smaller than `width` by `height` are padded with `background`,
+    /// larger ones are cut off.
+    ///
+    /// # Errors
+    /// When the size does not fit in a GIF or writing the header fails.
+    pub fn new(
+        out: W,
+        width: usize,
+        height: usize,
+        background: Rgb,
+        delay: Duration,
+    ) -> io::Result<Self> {
+        let size = |v: usize| u16::try_from(v.max(1)).map_err(io::Error::other);
+        let (width, height) = (size(width)?, size(height)?);
+        let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            background,
+            delay,
+        })
+    }
+
+    /// # Errors
+    /// When writing the frame fails.
+    pub fn push(&mut self, image: &Image) -> io::Result<()> {
+        let (width, height) = (usize::from(self.width), usize::from(self.height));
+        let mut canvas = Image::new(width, height, self.background);
+        for y in 0..height.min(image.height()) {
+            let row = &image.pixels.row(y)[..width.min(image.width())];
+            canvas.pixels.row_mut(y)[..row.len()].copy_from_slice(row);
+        }
+
+        let mut colors: Vec<Rgb> = Vec::new();
+        let mut indices = Vec::with_capacity(canvas.pixels.len());
+        for idx in 0..canvas.pixels.len() {
+            let pixel = canvas.pixels[idx];
+            let i = match colors.iter().position(|&c| c == pixel) {
+                Some(i) => i,
+                None => {
+                    colors.push(pixel);
+                    colors.len() - 1
+                }
+            };
+            if colors.len() > 256 {
+                break;
+            }
+            indices.push(u8::try_from(i).unwrap());
+        }
+
+        let mut frame = if colors.len() > 256 {
+            gif::Frame::from_rgb_speed(self.width, self.height, &canvas.bytes(), 10)
+        } else {
+            gif::Frame {
+                width: self.width,
+                height: self.height,
+                buffer: Cow::Owned(indices),
+                palette: Some(colors.concat()),
+                ..gif::Frame::default()
+            }
+        };
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+
+    /// Ends the GIF and hands back the writer.
+    ///
+    /// # Errors
+    /// When writing the end of the GIF fails.
+    pub fn finish(self) -> io::Result<W> {
+        self.encoder.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{heat, GifWriter, Image, Palette};
+    use crate::{Frame, Grid, Loc};
+    use std::time::Duration;
+
+    const WALL: u8 = b'#';
+    const SAND: u8 = b'o';
+
+    #[test]
+    fn test_image() {
+        let grid = Grid::from_vec(3, b"..#.o#".to_vec());
+        let palette = Palette::new([0, 0, 0])
+            .with(WALL, [9, 9, 9])
+            .with(SAND, [1, 2, 3]);
+        let image = Image::from_grid(&grid, |c| palette.color(c));
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.get(1, 1), Some([1, 2, 3]));
+        assert_eq!(image.get(0, 0), Some([0, 0, 0]));
+
+        let trimmed = image.trimmed([0, 0, 0]);
+        assert_eq!((trimmed.width(), trimmed.height()), (2, 2));
+        assert_eq!(trimmed.get(1, 0), Some([9, 9, 9]));
+        assert_eq!(
+            image.cropped(Loc::new(2, 1), Loc::new(5, 5)).get(0, 0),
+            Some([9, 9, 9])
+        );
+        assert_eq!(image.cropped(Loc::new(4, 0), Loc::new(1, 1)).width(), 0);
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (6, 4));
+        assert_eq!(scaled.get(3, 3), Some([1, 2, 3]));
+
+        assert_eq!(heat(0, 9), [0, 0, 0]);
+        assert_eq!(heat(9, 9), [255, 255, 255]);
+
+        let mut frame = Frame::new("");
+        frame.set(0, 0, '#').paint(2, 0, 'H', 9);
+        let image = Image::from_frame(&frame, &Palette::frame(), Loc::new(80, 20));
+        assert_eq!((image.width(), image.height()), (3, 1));
+        assert_eq!(image.get(2, 0), Some([255, 0, 0]));
+    }
+
+    #[test]
+    fn test_export() {
+        let mut image = Image::new(4, 3, [0, 0, 0]);
+        image.set(1, 2, [255, 0, 0]);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let mut gif = Vec::new();
+        let mut writer = GifWriter::new(&mut gif, 4, 4, [0, 0, 0], Duration::ZERO).unwrap();
+        writer.push(&image).unwrap();
+        writer.push(&image.scaled(2)).unwrap();
+        writer.finish().unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert!(gif.ends_with(b";"));
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::ParseError;
+
+/// Puzzle input location, relative to the day crate.
+pub const INPUT_PATH: &str = "input/input.txt";
+
+pub fn read_input() -> Vec<u8> {
+    read_input_from(Path::new(INPUT_PATH)).unwrap_or_else(|e| panic!("{INPUT_PATH}: {e}"))
+}
+
+/// Puzzle input from `path`, or from stdin when it is `-`.
+pub fn read_input_from(path: &Path) -> io::Result<Vec<u8>> {
+    if path == Path::new("-") {
+        let mut input = Vec::<u8>::with_capacity(1_000_000);
+        io::stdin().read_to_end(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read(path)
+    }
+}
+
+pub fn read_input_string() -> String {
+    String::from_utf8(read_input()).expect("input is not valid UTF-8")
+}
+
+/// For the days that parse `&str`.
+pub fn as_str(input: &[u8]) -> Result<&str, ParseError> {
+    core::str::from_utf8(input)
+        .map_err(|e| ParseError::at(input, e.valid_up_to(), "input is not valid UTF-8"))
+}
+
+/// Lines without their `\n` or `\r\n`, trailing empty lines are dropped.
+///
+/// The lines are slices of `input`, so they can point a [`ParseError`] at the
+/// right spot.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let end = input
+        .iter()
+        .rposition(|&c| c != b'\n' && c != b'\r')
+        .map_or(0, |p| p + 1);
+    let input = &input[..end];
+
+    input
+        .split(|&c| c == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .take(if input.is_empty() { 0 } else { usize::MAX })
+}
+
+/// [`lines`] for the days that parse `&str`.
+pub fn str_lines(input: &str) -> impl Iterator<Item = &str> {
+    let input = input.trim_end_matches(['\r', '\n']);
+
+    input
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .take(if input.is_empty() { 0 } else { usize::MAX })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, read_input_from, str_lines};
+    use std::path::Path;
+
+    #[test]
+    fn test_lines() {
+        let want: Vec<&[u8]> = vec![b"1", b"", b"2"];
+        assert_eq!(lines(b"1\n\n2").collect::<Vec<_>>(), want);
+        assert_eq!(lines(b"1\n\n2\n").collect::<Vec<_>>(), want);
+        assert_eq!(lines(b"1\r\n\r\n2\r\n\r\n").collect::<Vec<_>>(), want);
+        assert_eq!(lines(b"").count(), 0);
+        assert_eq!(lines(b"\r\n").count(), 0);
+    }
+
+    #[test]
+    fn test_str_lines() {
+        let want = vec!["1", "", "2"];
+        assert_eq!(str_lines("1\n\n2").collect::<Vec<_>>(), want);
+        assert_eq!(str_lines("1\r\n\r\n2\r\n").collect::<Vec<_>>(), want);
+        assert_eq!(str_lines("\n").count(), 0);
+    }
+
+    #[test]
+    fn test_read_input_from() {
+        let input = read_input_from(Path::new("Cargo.toml")).unwrap();
+        assert!(input.starts_with(b"[package]"));
+        assert!(read_input_from(Path::new("no/such/input.txt")).is_err());
+    }
+}
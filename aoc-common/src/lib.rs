@@ -0,0 +1,25 @@
+mod error;
+mod grid;
+mod image;
+mod input;
+mod logger;
+mod number;
+mod render;
+mod search;
+mod snapshot;
+mod solution;
+mod types;
+
+pub use error::ParseError;
+pub use grid::Grid;
+pub use image::{heat, GifWriter, Image, Palette, Rgb};
+pub use input::{
+    as_str, lines, read_input, read_input_from, read_input_string, str_lines, INPUT_PATH,
+};
+pub use logger::init_logger;
+pub use number::{slice_to_number, try_slice_to_number, Number};
+pub use render::{Color, Frame, Renderer};
+pub use search::{astar, bfs, dijkstra, Paths};
+pub use snapshot::{text_grid, Snapshot, SnapshotHeader};
+pub use solution::{format_duration, print_result, run, Answer, Part, Solution};
+pub use types::{Idx, Loc};
@@ -0,0 +1,20 @@
+use log::LevelFilter;
+
+/// Diagnostics of the days go to stderr, answers stay on stdout.
+///
+/// Verbosity 0 only shows warnings, 1 adds `info!`, 2 `debug!` and 3 `trace!`.
+/// `RUST_LOG` can still pick levels per day, e.g. `RUST_LOG=day12=trace`.
+pub fn init_logger(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format_timestamp(None)
+        .init();
+}
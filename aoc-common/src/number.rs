@@ -0,0 +1,70 @@
+/// Unsigned integer that can be built digit by digit.
+pub trait Number: Copy {
+    const ZERO: Self;
+
+    /// `self * 10 + digit`, `None` on overflow.
+    fn push_digit(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+
+                fn push_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_add(Self::from(digit))
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, usize);
+
+pub fn slice_to_number<T: Number>(input: &[u8]) -> T {
+    input
+        .iter()
+        .map(|v| *v - b'0')
+        .fold(T::ZERO, |sum, x| sum.push_digit(x).unwrap())
+}
+
+/// Checked [`slice_to_number`], `None` on empty input, a non-digit or overflow.
+pub fn try_slice_to_number<T: Number>(input: &[u8]) -> Option<T> {
+    if input.is_empty() {
+        return None;
+    }
+    input.iter().try_fold(T::ZERO, |sum, v| {
+        v.is_ascii_digit()
+            .then(|| sum.push_digit(v - b'0'))
+            .flatten()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{slice_to_number, try_slice_to_number};
+
+    #[test]
+    fn test_slice_to_number() {
+        assert_eq!(slice_to_number::<u8>(b"0"), 0);
+        assert_eq!(slice_to_number::<u8>(b"255"), 255);
+        assert_eq!(slice_to_number::<u8>(b"0255"), 255);
+        assert_eq!(slice_to_number::<u16>(b"65535"), 65535);
+        assert_eq!(slice_to_number::<u64>(b"1000000000000"), 1_000_000_000_000);
+    }
+
+    #[test]
+    fn test_try_slice_to_number() {
+        assert_eq!(try_slice_to_number::<u8>(b"255"), Some(255));
+        assert_eq!(try_slice_to_number::<u8>(b"256"), None);
+        assert_eq!(try_slice_to_number::<u8>(b"2a"), None);
+        assert_eq!(try_slice_to_number::<u8>(b""), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_to_number_overflow() {
+        slice_to_number::<u8>(b"256");
+    }
+}
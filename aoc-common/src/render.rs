@@ -0,0 +1,314 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::{Grid, Loc};
+
+/// Index in the 256 colour palette of the terminal.
+pub type Color = u8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cell {
+    pub(crate) ch: char,
+    pub(crate) color: Option<Color>,
+}
+
+/// Picture of one step of a simulation.
+///
+/// Cells can be anywhere, negative coordinates included. Only the rectangle
+/// around the cells that are set gets drawn, the rest of it shows the
+/// background. A cell that is set twice shows the last value.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    caption: String,
+    background: char,
+    cells: Vec<(Loc<i64>, Cell)>,
+    min: Loc<i64>,
+    max: Loc<i64>,
+    focus: Option<Loc<i64>>,
+}
+
+fn coord(v: impl TryInto<i64>) -> i64 {
+    v.try_into()
+        .unwrap_or_else(|_| panic!("coordinate does not fit in i64"))
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>) -> Self {
+        Self {
+            caption: caption.into(),
+            background: '.',
+            cells: Vec::new(),
+            min: Loc::new(i64::MAX, i64::MAX),
+            max: Loc::new(i64::MIN, i64::MIN),
+            focus: None,
+        }
+    }
+
+    /// Every cell of `grid`, `cell` returns `None` for background.
+    pub fn from_grid<T>(
+        caption: impl Into<String>,
+        grid: &Grid<T>,
+        cell: impl Fn(&T) -> Option<char>,
+    ) -> Self {
+        let mut frame = Self::new(caption);
+        for idx in 0..grid.len() {
+            if let Some(ch) = cell(&grid[idx]) {
+                let loc = grid.loc(idx);
+                frame.set(loc.x, loc.y, ch);
+            }
+        }
+        frame
+    }
+
+    /// Shown for the cells that are not set, `.` by default.
+    pub fn background(&mut self, ch: char) -> &mut Self {
+        self.background = ch;
+        self
+    }
+
+    pub fn caption(&mut self, caption: impl Into<String>) -> &mut Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn set(&mut self, x: impl TryInto<i64>, y: impl TryInto<i64>, ch: char) -> &mut Self {
+        self.push(Loc::new(coord(x), coord(y)), Cell { ch, color: None })
+    }
+
+    /// [`Frame::set`] in a colour.
+    pub fn paint(
+        &mut self,
+        x: impl TryInto<i64>,
+        y: impl TryInto<i64>,
+        ch: char,
+        color: Color,
+    ) -> &mut Self {
+        let cell = Cell {
+            ch,
+            color: Some(color),
+        };
+        self.push(Loc::new(coord(x), coord(y)), cell)
+    }
+
+    /// Keeps `(x, y)` in view when the frame does not fit.
+    pub fn focus(&mut self, x: impl TryInto<i64>, y: impl TryInto<i64>) -> &mut Self {
+        self.focus = Some(Loc::new(coord(x), coord(y)));
+        self
+    }
+
+    fn push(&mut self, loc: Loc<i64>, cell: Cell) -> &mut Self {
+        self.min = Loc::new(self.min.x.min(loc.x), self.min.y.min(loc.y));
+        self.max = Loc::new(self.max.x.max(loc.x), self.max.y.max(loc.y));
+        self.cells.push((loc, cell));
+        self
+    }
+
+    /// Top left and size of the part that fits in `width` by `height`.
+    fn viewport(&self, width: usize, height: usize) -> (Loc<i64>, Loc<usize>) {
+        if self.cells.is_empty() {
+            return (Loc::default(), Loc::default());
+        }
+        let focus = self.focus.unwrap_or(self.min);
+        let fit = |min: i64, max: i64, focus: i64, len: usize| {
+            let full = usize::try_from(max - min + 1).unwrap_or(usize::MAX);
+            if full <= len {
+                return (min, full);
+            }
+            let len_i = coord(len);
+            let start = (focus - len_i / 2).clamp(min, max - len_i + 1);
+            (start, len)
+        };
+        let (x, w) = fit(self.min.x, self.max.x, focus.x, width);
+        let (y, h) = fit(self.min.y, self.max.y, focus.y, height);
+        (Loc::new(x, y), Loc::new(w, h))
+    }
+
+    /// The cells in view when at most `size` fits.
+    pub(crate) fn view(&self, size: Loc<usize>) -> Grid<Cell> {
+        let (start, size) = self.viewport(size.x, size.y);
+        let background = Cell {
+            ch: self.background,
+            color: None,
+        };
+        let mut view = Grid::new(size.x, size.y, background);
+        for &(loc, cell) in &self.cells {
+            let x = usize::try_from(loc.x - start.x).ok();
+            let y = usize::try_from(loc.y - start.y).ok();
+            if let (Some(x), Some(y)) = (x, y) {
+                if let Some(c) = view.get_mut(Loc::new(x, y)) {
+                    *c = cell;
+                }
+            }
+        }
+        view
+    }
+
+    fn render(&self, out: &mut impl fmt::Write, size: Loc<usize>, color: bool) -> fmt::Result {
+        if !self.caption.is_empty() {
+            writeln!(out, "{}", self.caption)?;
+        }
+
+        for row in self.view(size).rows() {
+            for cell in row {
+                match cell.color {
+                    Some(c) if color => write!(out, "\x1b[38;5;{c}m{}\x1b[0m", cell.ch)?,
+                    _ => write!(out, "{}", cell.ch)?,
+                }
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
+
+/// The whole frame, without colours.
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, Loc::new(usize::MAX, usize::MAX), false)
+    }
+}
+
+/// Draws frames on the terminal, or as plain text to any writer.
+pub struct Renderer<W> {
+    out: W,
+    size: Loc<usize>,
+    color: bool,
+    /// Draw over the previous frame instead of below it.
+    clear: bool,
+    delay: Duration,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+/// `$COLUMNS` by `$LINES` minus the caption, or 80 by 24.
+fn terminal_size() -> Loc<usize> {
+    let var = |name, default| {
+        std::env::var(name)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(default)
+    };
+    Loc::new(var("COLUMNS", 80), var("LINES", 24_usize).saturating_sub(1))
+}
+
+impl Renderer<io::Stdout> {
+    /// Animation on stdout, `delay` between the frames.
+    pub fn terminal(delay: Duration, color: bool) -> Self {
+        Self {
+            out: io::stdout(),
+            size: terminal_size(),
+            color,
+            clear: true,
+            delay,
+            frames: 0,
+            error: None,
+        }
+    }
+}
+
+impl<W: Write> Renderer<W> {
+    /// Frames as plain text one after the other, each followed by an empty
+    /// line, at most 120 by 60.
+    pub fn headless(out: W) -> Self {
+        Self {
+            out,
+            size: Loc::new(120, 60),
+            color: false,
+            clear: false,
+            delay: Duration::ZERO,
+            frames: 0,
+            error: None,
+        }
+    }
+
+    /// Largest part of a frame that gets drawn.
+    pub fn with_size(mut self, width: usize, height: usize) -> Self {
+        self.size = Loc::new(width, height);
+        self
+    }
+
+    /// Stops drawing after the first write error, [`Renderer::finish`]
+    /// returns it.
+    pub fn draw(&mut self, frame: &Frame) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let mut text = String::new();
+        if self.clear {
+            text.push_str("\x1b[H\x1b[2J");
+        }
+        frame
+            .render(&mut text, self.size, self.color)
+            .expect("writing to a String");
+        if !self.clear {
+            text.push('\n');
+        }
+
+        match self
+            .out
+            .write_all(text.as_bytes())
+            .and_then(|()| self.out.flush())
+        {
+            Ok(()) => self.frames += 1,
+            Err(e) => self.error = Some(e),
+        }
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+    }
+
+    /// Number of frames drawn.
+    ///
+    /// # Errors
+    /// The first error writing a frame.
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.frames),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, Renderer};
+    use crate::Grid;
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::new("round 1");
+        frame.set(-1, -1, '#').set(1, 0, '#').paint(0, 0, 'H', 1);
+        assert_eq!(frame.to_string(), "round 1\n#..\n.H#\n");
+
+        frame.background(' ').caption("").set(0, 0, 'T');
+        assert_eq!(frame.to_string(), "#  \n T#\n");
+
+        let grid = Grid::from_vec(3, b"..#.#.".to_vec());
+        let frame = Frame::from_grid("", &grid, |&c| (c == b'#').then_some('#'));
+        assert_eq!(frame.to_string(), ".#\n#.\n");
+        assert_eq!(Frame::new("").to_string(), "");
+    }
+
+    #[test]
+    fn test_headless() {
+        let mut frame = Frame::new("");
+        for x in 0..10 {
+            frame.set(x, x % 2, char::from(b'0' + x as u8));
+        }
+        frame.focus(9, 0);
+
+        let mut out = Vec::new();
+        let mut renderer = Renderer::headless(&mut out).with_size(4, 1);
+        renderer.draw(&frame);
+        frame.paint(0, 0, '*', 2).focus(0, 0);
+        renderer.draw(&frame);
+        assert_eq!(renderer.finish().unwrap(), 2);
+
+        assert_eq!(String::from_utf8(out).unwrap(), "6.8.\n\n*.2.\n\n");
+    }
+}
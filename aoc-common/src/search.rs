@@ -0,0 +1,236 @@
+use ahash::{AHashMap, AHashSet};
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Outcome of a search from one start node.
+///
+/// Without a goal, or when none was reached, the whole reachable graph is
+/// searched and every distance is the shortest one.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    dist: AHashMap<N, C>,
+    prev: AHashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        let mut dist = AHashMap::new();
+        dist.insert(start, zero);
+        Self {
+            dist,
+            prev: AHashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The first node that matched the goal.
+    pub fn goal(&self) -> Option<N> {
+        self.goal
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// Cost to reach the goal.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.and_then(|goal| self.distance(&goal))
+    }
+
+    /// All nodes seen so far with their cost.
+    pub fn distances(&self) -> impl Iterator<Item = (N, C)> + '_ {
+        self.dist.iter().map(|(&n, &c)| (n, c))
+    }
+
+    /// Nodes from the start up to and including `to`.
+    pub fn path(&self, to: N) -> Option<Vec<N>> {
+        self.dist.get(&to)?;
+
+        let mut path = vec![to];
+        while let Some(&prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, every step costs 1. Stops at the first node for which
+/// `goal` holds.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start, 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let dist = paths.dist[&node] + 1;
+        for next in neighbours(&node) {
+            if let Entry::Vacant(e) = paths.dist.entry(next) {
+                e.insert(dist);
+                paths.prev.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Cheapest paths, `neighbours` gives each next node with the cost to get
+/// there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must be consistent: never more
+/// than the cost of a step plus the heuristic of where it leads. Nodes are not
+/// revisited once done, so a heuristic that is only admissible can miss the
+/// shortest path.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start, C::default());
+    let mut done = AHashSet::new();
+    let mut queue = BinaryHeap::from([Queued(heuristic(&start), start)]);
+
+    while let Some(Queued(_, node)) = queue.pop() {
+        if !done.insert(node) {
+            continue;
+        }
+        if goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let cost = paths.dist[&node];
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            if paths.dist.get(&next).is_none_or(|&old| cost < old) {
+                paths.dist.insert(next, cost);
+                paths.prev.insert(next, node);
+                queue.push(Queued(cost + heuristic(&next), next));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Queue entry, the cheapest comes out of the [`BinaryHeap`] first.
+struct Queued<N, C>(C, N);
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+
+    //  0 - 1 - 2
+    //  |       |
+    //  3 ----- 4 - 5
+    const EDGES: [(u8, u8, u32); 6] = [
+        (0, 1, 1),
+        (1, 2, 1),
+        (0, 3, 1),
+        (2, 4, 1),
+        (3, 4, 5),
+        (4, 5, 1),
+    ];
+
+    fn neighbours(node: &u8) -> Vec<(u8, u32)> {
+        EDGES
+            .iter()
+            .filter_map(|&(a, b, cost)| match *node {
+                n if n == a => Some((b, cost)),
+                n if n == b => Some((a, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let steps = |n: &u8| neighbours(n).into_iter().map(|(n, _)| n);
+
+        let paths = bfs(0, steps, |&n| n == 5);
+        assert_eq!(paths.goal(), Some(5));
+        assert_eq!(paths.goal_distance(), Some(3));
+        assert_eq!(paths.path(5), Some(vec![0, 3, 4, 5]));
+
+        let paths = bfs(0, steps, |_| false);
+        assert_eq!(paths.goal(), None);
+        assert_eq!(paths.distances().count(), 6);
+        assert_eq!(paths.distance(&2), Some(2));
+        assert_eq!(paths.path(9), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra(0, neighbours, |&n| n == 5);
+        assert_eq!(paths.goal_distance(), Some(4));
+        assert_eq!(paths.path(5), Some(vec![0, 1, 2, 4, 5]));
+
+        let paths = dijkstra(5, neighbours, |_| false);
+        assert_eq!(paths.distance(&3), Some(5));
+        assert_eq!(paths.path(5), Some(vec![5]));
+    }
+
+    #[test]
+    fn test_astar() {
+        // Consistent, drops by at most the step cost towards 5.
+        let heuristic = |n: &u8| [2, 2, 1, 1, 0, 0][usize::from(*n)];
+        let paths = astar(0, neighbours, heuristic, |&n| n == 5);
+        assert_eq!(paths.goal_distance(), Some(4));
+        assert_eq!(paths.path(5), Some(vec![0, 1, 2, 4, 5]));
+    }
+}
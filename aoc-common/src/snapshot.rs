@@ -0,0 +1,117 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::Part;
+
+/// State of a simulation part way through a part, to stop a run, look at it
+/// and resume it later. Saved as JSON.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Snapshot<T> {
+    pub day: u8,
+    pub part: Part,
+    /// Rounds, rocks or grains done, whatever the day counts.
+    pub step: usize,
+    pub state: T,
+}
+
+/// What a snapshot is of, without reading the state.
+#[derive(Debug, Deserialize)]
+pub struct SnapshotHeader {
+    pub day: u8,
+    pub part: Part,
+    pub step: usize,
+}
+
+impl SnapshotHeader {
+    /// # Errors
+    /// When `json` is not a snapshot.
+    pub fn read(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+impl<T: Serialize> Snapshot<T> {
+    /// # Panics
+    /// When the state does not fit in JSON, like a map with non-string keys.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("state fits in JSON")
+    }
+}
+
+impl<T: DeserializeOwned> Snapshot<T> {
+    /// # Errors
+    /// When `json` is not a snapshot of `day`.
+    pub fn from_json(json: &str, day: u8) -> Result<Self, String> {
+        let header = SnapshotHeader::read(json)?;
+        if header.day != day {
+            return Err(format!("snapshot of day {}, not of day {day}", header.day));
+        }
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+/// Serde for byte grids as one string per row, for `#[serde(with = ...)]`.
+pub mod text_grid {
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Grid;
+
+    /// # Errors
+    /// When a row is not valid UTF-8.
+    pub fn serialize<S: Serializer>(grid: &Grid<u8>, s: S) -> Result<S::Ok, S::Error> {
+        let rows = grid
+            .rows()
+            .map(|row| core::str::from_utf8(row).map_err(ser::Error::custom))
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.serialize(s)
+    }
+
+    /// # Errors
+    /// When the rows differ in length.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Grid<u8>, D::Error> {
+        let rows = Vec::<String>::deserialize(d)?;
+        let width = rows.first().map_or(0, String::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(de::Error::custom("rows differ in length"));
+        }
+        Ok(Grid::from_vec(width, rows.concat().into_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Snapshot, SnapshotHeader};
+    use crate::{Grid, Part};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct State {
+        #[serde(with = "super::text_grid")]
+        grid: Grid<u8>,
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let state = State {
+            grid: Grid::from_vec(3, b"#..##.".to_vec()),
+        };
+        let snapshot = Snapshot {
+            day: 14,
+            part: Part::Two,
+            step: 5,
+            state,
+        };
+        let json = snapshot.to_json();
+        assert!(json.contains(r##""#..""##));
+
+        let header = SnapshotHeader::read(&json).unwrap();
+        assert_eq!((header.day, header.part, header.step), (14, Part::Two, 5));
+        let back = Snapshot::<State>::from_json(&json, 14).unwrap();
+        assert_eq!((back.part, back.step), (Part::Two, 5));
+        assert_eq!(back.state, snapshot.state);
+
+        let e = Snapshot::<State>::from_json(&json, 17).unwrap_err();
+        assert_eq!(e, "snapshot of day 14, not of day 17");
+        let json = json.replace(r##""#..""##, r##""#.""##);
+        assert!(Snapshot::<State>::from_json(&json, 14).is_err());
+    }
+}
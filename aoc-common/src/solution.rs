@@ -0,0 +1,233 @@
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::{CommandFactory, FromArgMatches, Parser};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::{Frame, Image, ParseError};
+
+/// Answer of a single puzzle part.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Num(i64),
+    Text(String),
+    /// Part has no solver (yet).
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Num(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    (lossless: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Self::Num(i64::from(v))
+                }
+            }
+        )*
+    };
+    (checked: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Self::Num(i64::try_from(v).unwrap_or_else(|_| panic!("answer {v} does not fit in i64")))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(lossless: u8, u16, u32, i8, i16, i32, i64);
+impl_answer_from!(checked: u64, usize, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_owned())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// One day of the calendar, solvable from the raw puzzle input.
+pub trait Solution: Sync {
+    fn day(&self) -> u8;
+
+    /// Only parses the input, to time the parse apart from the parts.
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError>;
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError>;
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError>;
+
+    fn solve(&self, part: Part, input: &[u8]) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    /// [`Solution::solve`] that hands a [`Frame`] to `draw` after every step
+    /// of the simulation. Days without one draw nothing.
+    fn animate(
+        &self,
+        part: Part,
+        input: &[u8],
+        _draw: &mut dyn FnMut(&Frame),
+    ) -> Result<Answer, ParseError> {
+        self.solve(part, input)
+    }
+
+    /// Picture of the puzzle once it is solved, `None` for the days without
+    /// one.
+    fn image(&self, _input: &[u8]) -> Result<Option<Image>, ParseError> {
+        Ok(None)
+    }
+
+    /// Random input that keeps to the rules of the puzzle, about `size` lines
+    /// or items long. `None` for the days without a generator.
+    fn generate(&self, _rng: &mut dyn RngCore, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// [`Snapshot`](crate::Snapshot) in JSON of `part` after `steps` steps of
+    /// the simulation, `None` for the days without one.
+    fn snapshot(
+        &self,
+        _part: Part,
+        _input: &[u8],
+        _steps: usize,
+    ) -> Result<Option<String>, ParseError> {
+        Ok(None)
+    }
+
+    /// Finishes the run saved by [`Solution::snapshot`], `None` for the days
+    /// without one.
+    ///
+    /// # Errors
+    /// When `snapshot` is not a snapshot of this day.
+    fn resume(&self, _snapshot: &str) -> Result<Option<Answer>, String> {
+        Ok(None)
+    }
+
+    fn timed(&self, part: Part, input: &[u8]) -> (Result<Answer, ParseError>, Duration) {
+        let start = Instant::now();
+        let answer = self.solve(part, input);
+        (answer, start.elapsed())
+    }
+}
+
+/// Human friendly duration, e.g. `153 uS` or `12.4 mS`.
+pub fn format_duration(d: Duration) -> String {
+    let us = d.as_micros();
+    if us < 10_000 {
+        format!("{us} uS")
+    } else if us < 10_000_000 {
+        format!("{:.1} mS", d.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2} S", d.as_secs_f64())
+    }
+}
+
+/// Prints one uniform result line, multi-line answers start on a new line.
+pub fn print_result(day: u8, part: Part, answer: &Answer, time: Duration) {
+    let answer = answer.to_string();
+    let sep = if answer.contains('\n') { "\n" } else { " " };
+    println!(
+        "Day {day:2} part {part}:{sep}{answer} ({})",
+        format_duration(time)
+    );
+}
+
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, `-` reads stdin.
+    #[arg(short, long, value_name = "FILE", default_value = crate::INPUT_PATH)]
+    input: PathBuf,
+}
+
+/// Body of every day binary: solve both parts of `input/input.txt`, or of the
+/// file given with `--input`.
+pub fn run(solution: &dyn Solution) -> ExitCode {
+    let day = solution.day();
+    let matches = Args::command()
+        .about(format!("Advent of Code 2022 day {day}"))
+        .get_matches();
+    let path = Args::from_arg_matches(&matches).map_or_else(|e| e.exit(), |args| args.input);
+
+    crate::init_logger(0);
+    let input = match crate::read_input_from(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for part in Part::ALL {
+        match solution.timed(part, &input) {
+            (Ok(answer), time) => print_result(solution.day(), part, &answer, time),
+            (Err(e), _) => {
+                eprintln!("{}:{e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(24_000_u32), Answer::Num(24_000));
+        assert_eq!(Answer::from(-3_i64), Answer::Num(-3));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_owned()));
+        assert_eq!(Answer::Unsolved.to_string(), "-");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_answer_overflow() {
+        let _ = Answer::from(u64::MAX);
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, SubAssign},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, PartialOrd, Ord, Hash, Default)]
+pub struct Idx<T = usize>(pub T);
+
+impl<T: Display> Display for Idx<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Idx: {}", self.0)
+    }
+}
+
+impl<T: Copy + Into<usize>> Idx<T> {
+    pub fn as_usize(&self) -> usize {
+        self.0.into()
+    }
+}
+
+impl<T: TryFrom<usize>> Idx<T> {
+    pub fn from(idx: usize) -> Self {
+        Idx(T::try_from(idx).unwrap_or_else(|_| {
+            panic!(
+                "Index {idx} higher then {}::MAX",
+                std::any::type_name::<T>()
+            )
+        }))
+    }
+}
+
+impl<T: AddAssign + From<u8>> Idx<T> {
+    pub fn next(&mut self) {
+        self.0 += T::from(1);
+    }
+}
+
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub struct Loc<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Loc<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: AddAssign + SubAssign + From<u8>> Loc<T> {
+    pub fn up(&mut self) {
+        self.y += T::from(1);
+    }
+
+    pub fn down(&mut self) {
+        self.y -= T::from(1);
+    }
+
+    pub fn left(&mut self) {
+        self.x -= T::from(1);
+    }
+
+    pub fn right(&mut self) {
+        self.x += T::from(1);
+    }
+}
+
+impl<T: Add<Output = T>> Add for Loc<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Loc::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Display> Display for Loc<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Idx, Loc};
+
+    #[test]
+    fn test_idx() {
+        let mut idx = Idx::<u16>::from(41);
+        idx.next();
+        assert_eq!(idx, Idx(42));
+        assert_eq!(idx.as_usize(), 42);
+        assert_eq!(idx.to_string(), "Idx: 42");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_idx_overflow() {
+        Idx::<u8>::from(256);
+    }
+
+    #[test]
+    fn test_loc() {
+        let mut loc = Loc::<i32>::default();
+        loc.up();
+        loc.right();
+        loc.right();
+        assert_eq!(loc, Loc::new(2, 1));
+        loc.down();
+        loc.left();
+        assert_eq!(loc + Loc::new(-1, 5), Loc::new(0, 5));
+        assert_eq!(loc.to_string(), "1x0");
+    }
+}
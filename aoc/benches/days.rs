@@ -0,0 +1,234 @@
+//! Parse, part 1 and part 2 of every day, on the example and, when it is
+//! there, on `dayN/input/input.txt`.
+//!
+//! `cargo bench -p aoc -- day12/input` runs a single day.
+
+use aoc_common::{as_str, Answer, ParseError};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::path::Path;
+
+struct Input {
+    name: &'static str,
+    data: Vec<u8>,
+}
+
+impl Input {
+    fn is_example(&self) -> bool {
+        self.name == "example"
+    }
+}
+
+fn inputs(day: u8) -> Vec<Input> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day}"))
+        .join("input");
+
+    [("example", "example.txt"), ("input", "input.txt")]
+        .into_iter()
+        .filter_map(|(name, file)| {
+            let data = std::fs::read(dir.join(file)).ok()?;
+            Some(Input { name, data })
+        })
+        .collect()
+}
+
+type Part<'p, T> = (&'static str, &'p dyn Fn(&T) -> Answer);
+
+fn bench<'a, T>(
+    c: &mut Criterion,
+    day: u8,
+    input: &'a Input,
+    parse: impl Fn(&'a [u8]) -> Result<T, ParseError>,
+    parts: &[Part<'_, T>],
+) {
+    let mut group = c.benchmark_group(format!("day{day}/{}", input.name));
+
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(&input.data))));
+
+    let parsed = parse(&input.data).unwrap_or_else(|e| panic!("day{day}/{}:{e}", input.name));
+    for (name, part) in parts {
+        group.bench_function(*name, |b| b.iter(|| part(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+/// Benchmarks `dayN::parse` and the listed parts of `dayN` on all its inputs.
+macro_rules! day {
+    ($c:expr, $day:ident = $n:literal, $parse:expr, $($part:ident),+) => {
+        for input in &inputs($n) {
+            let parse = $parse;
+            bench($c, $n, input, parse, &[
+                $((stringify!($part), &|p| $day::$part(p).into())),+
+            ]);
+        }
+    };
+}
+
+fn days(c: &mut Criterion) {
+    for input in &inputs(1) {
+        bench(
+            c,
+            1,
+            input,
+            day1::parse,
+            &[
+                ("part1", &|h| day1::part1(h).into()),
+                ("part2", &|h| {
+                    day1::part2(h).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    day!(c, day2 = 2, day2::parse, part1, part2);
+    day!(c, day3 = 3, day3::parse, part1, part2);
+    day!(c, day4 = 4, day4::parse, part1, part2);
+    for input in &inputs(5) {
+        bench(
+            c,
+            5,
+            input,
+            day5::parse,
+            &[
+                ("part1", &|d| {
+                    day5::part1(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+                ("part2", &|d| {
+                    day5::part2(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    for input in &inputs(6) {
+        bench(
+            c,
+            6,
+            input,
+            day6::parse,
+            &[
+                ("part1", &|d| {
+                    day6::part1(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+                ("part2", &|d| {
+                    day6::part2(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    day!(c, day7 = 7, |i| day7::parse(as_str(i)?), part1, part2);
+    day!(c, day8 = 8, day8::parse, part1, part2);
+    day!(c, day9 = 9, day9::parse, part1, part2);
+    day!(c, day10 = 10, day10::parse, part1, part2);
+    for input in &inputs(11) {
+        bench(
+            c,
+            11,
+            input,
+            |i| day11::parse(as_str(i)?),
+            &[
+                ("part1", &|d| {
+                    day11::part1(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+                ("part2", &|d| {
+                    day11::part2(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    for input in &inputs(12) {
+        bench(
+            c,
+            12,
+            input,
+            day12::parse,
+            &[
+                ("part1", &|n| {
+                    day12::part1(n).map_or(Answer::Unsolved, Answer::from)
+                }),
+                ("part2", &|n| {
+                    day12::part2(n).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    day!(c, day13 = 13, |i| day13::parse(as_str(i)?), part1, part2);
+    day!(c, day14 = 14, day14::parse, part1, part2);
+
+    for input in &inputs(15) {
+        let (y_row, n_max) = if input.is_example() {
+            (10, 20)
+        } else {
+            (day15::Y_ROW, day15::N_MAX)
+        };
+        bench(
+            c,
+            15,
+            input,
+            day15::parse,
+            &[
+                ("part1", &|d| day15::part1(d, y_row).into()),
+                ("part2", &|d| {
+                    day15::part2(d, n_max).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+
+    day!(c, day16 = 16, |i| day16::parse(as_str(i)?), part1, part2);
+    for input in &inputs(17) {
+        bench(
+            c,
+            17,
+            input,
+            day17::parse,
+            &[
+                ("part1", &|j| day17::part1(j).into()),
+                ("part2", &|j| {
+                    day17::part2(j).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    day!(c, day18 = 18, day18::parse, part1, part2);
+    day!(c, day19 = 19, |i| day19::parse(as_str(i)?), part1, part2);
+    day!(c, day20 = 20, |i| day20::parse(as_str(i)?), part1, part2);
+    for input in &inputs(21) {
+        bench(
+            c,
+            21,
+            input,
+            |i| day21::parse(as_str(i)?),
+            &[
+                ("part1", &|d: &Vec<_>| {
+                    day21::part1(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+                ("part2", &|d: &Vec<_>| {
+                    day21::part2(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    day!(c, day22 = 22, day22::parse, part1);
+    day!(c, day23 = 23, day23::parse, part1, part2);
+    for input in &inputs(24) {
+        bench(
+            c,
+            24,
+            input,
+            day24::parse,
+            &[
+                ("part1", &|d| {
+                    day24::part1(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+                ("part2", &|d| {
+                    day24::part2(d).map_or(Answer::Unsolved, Answer::from)
+                }),
+            ],
+        );
+    }
+    day!(c, day25 = 25, day25::parse, part1);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
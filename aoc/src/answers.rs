@@ -0,0 +1,158 @@
+use aoc_common::{Answer, Part};
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+use toml::{Table, Value};
+
+/// Known good answers of one puzzle input, e.g.
+///
+/// ```toml
+/// part1 = 6087
+/// part2 = "CMZ"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers([Option<Answer>; 2]);
+
+/// Outcome of comparing a computed answer with the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict<'a> {
+    Ok,
+    Mismatch {
+        expected: &'a Answer,
+    },
+    /// Nothing recorded yet for this part.
+    New,
+    /// Recorded, but the part has no solver anymore.
+    Missing {
+        expected: &'a Answer,
+    },
+    /// The part has no solver.
+    Unsolved,
+}
+
+/// `answers.toml` next to the puzzle input.
+pub fn path(input: &Path) -> PathBuf {
+    input.with_file_name("answers.toml")
+}
+
+impl Answers {
+    /// A missing file is the same as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => text.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        self.0[part as usize] = Some(answer);
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Verdict<'_> {
+        match (&self.0[part as usize], answer) {
+            (Some(expected), Answer::Unsolved) => Verdict::Missing { expected },
+            (None, Answer::Unsolved) => Verdict::Unsolved,
+            (None, _) => Verdict::New,
+            (Some(expected), _) if expected == answer => Verdict::Ok,
+            (Some(expected), _) => Verdict::Mismatch { expected },
+        }
+    }
+}
+
+fn key(part: Part) -> String {
+    format!("part{part}")
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_owned())?;
+
+        let mut answers = Self::default();
+        for (name, value) in table {
+            let Some(part) = Part::ALL.into_iter().find(|&p| key(p) == name) else {
+                return Err(format!("unknown key `{name}`"));
+            };
+            let answer = match value {
+                Value::Integer(n) => Answer::Num(n),
+                Value::String(s) => Answer::Text(s),
+                v => {
+                    return Err(format!(
+                        "`{name}` must be a number or string, not {}",
+                        v.type_str()
+                    ))
+                }
+            };
+            answers.set(part, answer);
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new();
+        for part in Part::ALL {
+            let value = match &self.0[part as usize] {
+                Some(Answer::Num(n)) => Value::Integer(*n),
+                Some(Answer::Text(s)) => Value::String(s.clone()),
+                Some(Answer::Unsolved) | None => continue,
+            };
+            table.insert(key(part), value);
+        }
+        write!(f, "{table}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+    use aoc_common::{Answer, Part};
+
+    #[test]
+    fn test_round_trip() {
+        let answers: Answers = "part1 = 6087\npart2 = \"##..\\n..##\"\n".parse().unwrap();
+        assert_eq!(answers.check(Part::One, &Answer::Num(6087)), Verdict::Ok);
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+
+        assert_eq!(
+            "part3 = 1".parse::<Answers>().unwrap_err(),
+            "unknown key `part3`"
+        );
+        assert!("part1 = 1.5".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, Answer::Num(13));
+
+        assert_eq!(
+            answers.check(Part::One, &Answer::Num(14)),
+            Verdict::Mismatch {
+                expected: &Answer::Num(13)
+            }
+        );
+        assert_eq!(answers.check(Part::Two, &Answer::Num(14)), Verdict::New);
+        assert_eq!(
+            answers.check(Part::Two, &Answer::Unsolved),
+            Verdict::Unsolved
+        );
+        assert_eq!(
+            answers.check(Part::One, &Answer::Unsolved),
+            Verdict::Missing {
+                expected: &Answer::Num(13)
+            }
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_common::Solution;
+
+pub static DAYS: &[&dyn Solution] = &[
+    &day1::Solver,
+    &day2::Solver,
+    &day3::Solver,
+    &day4::Solver,
+    &day5::Solver,
+    &day6::Solver,
+    &day7::Solver,
+    &day8::Solver,
+    &day9::Solver,
+    &day10::Solver,
+    &day11::Solver,
+    &day12::Solver,
+    &day13::Solver,
+    &day14::Solver,
+    &day15::Solver,
+    &day16::Solver,
+    &day17::Solver,
+    &day18::Solver,
+    &day19::Solver,
+    &day20::Solver,
+    &day21::Solver,
+    &day22::Solver,
+    &day23::Solver,
+    &day24::Solver,
+    &day25::Solver,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Solution> {
+    DAYS.iter().copied().find(|s| s.day() == day)
+}
@@ -0,0 +1,93 @@
+use aoc_common::{Answer, Frame, GifWriter, Image, Loc, Palette, Part, Solution};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+/// Most cells of a frame that make it into a GIF.
+const GIF_CELLS: Loc<usize> = Loc::new(160, 100);
+
+fn create(path: &Path) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Picture of the solved puzzle, `scale` pixels per cell.
+pub fn png(
+    solution: &dyn Solution,
+    input: (&Path, &[u8]),
+    out: &Path,
+    scale: usize,
+) -> Result<(), String> {
+    let image = solution
+        .image(input.1)
+        .map_err(|e| format!("{}:{e}", input.0.display()))?
+        .ok_or_else(|| format!("day {} has no picture", solution.day()))?;
+
+    let mut file = create(out)?;
+    image
+        .scaled(scale)
+        .write_png(&mut file)
+        .and_then(|()| file.flush())
+        .map_err(|e| format!("{}: {e}", out.display()))
+}
+
+/// Every frame of the animation, `scale` pixels per cell, and the answers.
+/// The days run twice, the first time to find the largest frame.
+pub fn gif(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: (&Path, &[u8]),
+    out: &Path,
+    delay: Duration,
+    scale: usize,
+) -> Result<(Vec<(Part, Answer)>, usize), String> {
+    let palette = Palette::frame();
+    let image = |frame: &Frame| Image::from_frame(frame, &palette, GIF_CELLS);
+    let parse_error = |e| format!("{}:{e}", input.0.display());
+
+    let mut size = Loc::new(0, 0);
+    for &part in parts {
+        solution
+            .animate(part, input.1, &mut |frame| {
+                let image = image(frame);
+                size = Loc::new(size.x.max(image.width()), size.y.max(image.height()));
+            })
+            .map_err(parse_error)?;
+    }
+
+    let write_error = |e| format!("{}: {e}", out.display());
+    let background = palette.color(&'.');
+    let (width, height) = (size.x * scale, size.y * scale);
+    let mut gif =
+        GifWriter::new(create(out)?, width, height, background, delay).map_err(write_error)?;
+
+    let mut answers = Vec::with_capacity(parts.len());
+    let mut frames = 0;
+    let mut error = None;
+    for &part in parts {
+        let answer = solution
+            .animate(part, input.1, &mut |frame| {
+                if error.is_none() {
+                    match gif.push(&image(frame).scaled(scale)) {
+                        Ok(()) => frames += 1,
+                        Err(e) => error = Some(e),
+                    }
+                }
+            })
+            .map_err(parse_error)?;
+        answers.push((part, answer));
+    }
+
+    if let Some(e) = error {
+        return Err(write_error(e));
+    }
+    gif.finish()
+        .and_then(|mut out| out.flush())
+        .map_err(write_error)?;
+
+    Ok((answers, frames))
+}
@@ -0,0 +1,98 @@
+use aoc_common::{Answer, ParseError, Part, Solution};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+
+fn answer(answer: Answer) -> Value {
+    match answer {
+        Answer::Num(n) => n.into(),
+        Answer::Text(s) => s.into(),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+pub fn input_hash(input: &[u8]) -> String {
+    Sha256::digest(input)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// One record per part. The parse is timed on its own and taken off the time
+/// of each part, so `solve_ns` is only the solver.
+pub fn solve(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: &[u8],
+) -> Result<Vec<Value>, ParseError> {
+    let hash = input_hash(input);
+
+    let start = Instant::now();
+    solution.parse(input)?;
+    let parse = start.elapsed();
+
+    parts
+        .iter()
+        .map(|&part| {
+            let (result, time) = solution.timed(part, input);
+            Ok(json!({
+                "day": solution.day(),
+                "part": part as u8 + 1,
+                "answer": answer(result?),
+                "parse_ns": nanos(parse),
+                "solve_ns": nanos(time.saturating_sub(parse)),
+                "input_sha256": hash,
+            }))
+        })
+        .collect()
+}
+
+/// Record for a day without answers.
+pub fn error(day: u8, msg: String) -> Value {
+    json!({ "day": day, "error": msg })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_hash, solve};
+    use aoc_common::Part;
+    use serde_json::json;
+
+    #[test]
+    fn test_solve() {
+        let input = b"A Y\nB X\nC Z\n";
+        let mut records = solve(&day2::Solver, &Part::ALL, input).unwrap();
+
+        for record in &mut records {
+            assert!(record["parse_ns"].is_u64());
+            assert!(record["solve_ns"].is_u64());
+            record["parse_ns"] = json!(0);
+            record["solve_ns"] = json!(0);
+        }
+        let hash = input_hash(input);
+        assert_eq!(
+            records,
+            [
+                json!({"day": 2, "part": 1, "answer": 15, "parse_ns": 0, "solve_ns": 0, "input_sha256": hash}),
+                json!({"day": 2, "part": 2, "answer": 12, "parse_ns": 0, "solve_ns": 0, "input_sha256": hash}),
+            ]
+        );
+
+        assert_eq!(
+            solve(&day2::Solver, &Part::ALL, b"A W").unwrap_err().column,
+            1
+        );
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
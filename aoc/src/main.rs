@@ -0,0 +1,689 @@
+use aoc_common::{
+    format_duration, init_logger, print_result, read_input_from, Answer, Part, Renderer,
+    SnapshotHeader, Solution, INPUT_PATH,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use rand::{rngs::SmallRng, SeedableRng};
+use std::{
+    fs::File,
+    hash::{BuildHasher, Hasher, RandomState},
+    io::{self, BufWriter, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+mod answers;
+mod days;
+mod export;
+mod json;
+mod parallel;
+mod report;
+
+use answers::{Answers, Verdict};
+use report::Row;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    /// Show the diagnostics of the days, -vvv for all of them.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or all days in sequence when no day is given.
+    Run {
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input, `-` reads stdin. Defaults to dayN/input/input.txt.
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Solve all days at the same time and check them against
+        /// dayN/input/answers.toml.
+        #[arg(long, conflicts_with_all = ["day", "format"])]
+        parallel: bool,
+
+        /// Threads for --parallel, defaults to one per CPU.
+        #[arg(short, long, requires = "parallel", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+
+        /// Draw the simulation step by step, for the days that have one.
+        #[arg(long, requires = "day", conflicts_with = "format")]
+        animate: bool,
+
+        /// Milliseconds between two frames.
+        #[arg(long, default_value_t = 50, requires = "animate")]
+        delay: u64,
+
+        /// Write the frames as plain text to a file instead of the terminal.
+        #[arg(long, value_name = "FILE", requires = "animate")]
+        frames: Option<PathBuf>,
+
+        #[arg(long, requires = "animate")]
+        no_color: bool,
+
+        /// Write the frames to an animated GIF instead of the terminal.
+        #[arg(
+            long,
+            value_name = "FILE",
+            requires = "animate",
+            conflicts_with = "frames"
+        )]
+        gif: Option<PathBuf>,
+
+        /// Write a picture of the solved puzzle to a PNG, for the days that
+        /// have one.
+        #[arg(long, value_name = "FILE", requires = "day")]
+        png: Option<PathBuf>,
+
+        /// Pixels per cell in the GIF and PNG.
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(1..))]
+        scale: u8,
+    },
+    /// Compare answers with dayN/input/answers.toml.
+    Check {
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Save answers that are not in the answers file yet.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Print random puzzle input for a day, to stress test the solutions.
+    Gen {
+        #[arg(short, long)]
+        day: u8,
+
+        /// Rough number of lines or items, the days scale it to their rules.
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+
+        /// The same seed gives the same input, a random one is printed to stderr.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Write the input to a file instead of stdout.
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Save the state of a simulation part way through as JSON, for the days
+    /// that have one.
+    Snapshot {
+        #[arg(short, long)]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Puzzle input, `-` reads stdin. Defaults to dayN/input/input.txt.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// Rounds, rocks or grains to simulate before saving, whatever the
+        /// day counts.
+        #[arg(short, long)]
+        steps: usize,
+
+        /// Write the snapshot to a file instead of stdout.
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Finish the simulation saved by `aoc snapshot`.
+    Resume {
+        /// The snapshot, `-` reads stdin.
+        file: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    /// An array with a record per part: answer, parse and solve time, input hash.
+    Json,
+}
+
+fn default_input(day: u8) -> PathBuf {
+    Path::new(&format!("day{day}")).join(INPUT_PATH)
+}
+
+fn parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+fn run_day(solution: &dyn Solution, parts: &[Part], (path, input): (&Path, &[u8])) -> ExitCode {
+    for &part in parts {
+        match solution.timed(part, input) {
+            (Ok(answer), time) => print_result(solution.day(), part, &answer, time),
+            (Err(e), _) => {
+                eprintln!("{}:{e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn print_animated(day: u8, answers: &[(Part, Answer)], frames: usize) {
+    for (part, answer) in answers {
+        println!("Day {day:2} part {part}: {answer}");
+    }
+    println!("{frames} frames");
+}
+
+fn run_gif(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: (&Path, &[u8]),
+    out: &Path,
+    delay: Duration,
+    scale: usize,
+) -> ExitCode {
+    match export::gif(solution, parts, input, out, delay, scale) {
+        Ok((answers, frames)) => {
+            print_animated(solution.day(), &answers, frames);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn write_png(solution: &dyn Solution, input: (&Path, &[u8]), out: &Path, scale: usize) -> ExitCode {
+    match export::png(solution, input, out, scale) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_animated<W: Write>(
+    solution: &dyn Solution,
+    parts: &[Part],
+    (path, input): (&Path, &[u8]),
+    mut renderer: Renderer<W>,
+) -> ExitCode {
+    let mut answers = Vec::with_capacity(parts.len());
+    for &part in parts {
+        match solution.animate(part, input, &mut |frame| renderer.draw(frame)) {
+            Ok(answer) => answers.push((part, answer)),
+            Err(e) => {
+                eprintln!("{}:{e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match renderer.finish() {
+        Ok(frames) => {
+            print_animated(solution.day(), &answers, frames);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("drawing frames: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_all(parts: &[Part]) -> ExitCode {
+    let mut rows = Vec::with_capacity(days::DAYS.len());
+    let mut total = Duration::ZERO;
+    let mut failed = false;
+
+    'days: for solution in days::DAYS {
+        let day = solution.day();
+        let path = default_input(day);
+        let Ok(input) = std::fs::read(&path) else {
+            rows.push(Row::NoInput { day });
+            continue;
+        };
+
+        let mut answers = [Answer::Unsolved, Answer::Unsolved];
+        let mut time = Duration::ZERO;
+        for &part in parts {
+            let (answer, t) = solution.timed(part, &input);
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("{}:{e}", path.display());
+                    rows.push(Row::Failed { day });
+                    failed = true;
+                    continue 'days;
+                }
+            };
+            print_result(day, part, &answer, t);
+            answers[part as usize] = answer;
+            time += t;
+        }
+        total += time;
+
+        rows.push(Row::Solved { day, answers, time });
+    }
+
+    println!();
+    print!("{}", report::table(&rows));
+    println!("Total: {}", format_duration(total));
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_parallel(parts: &[Part], jobs: Option<u16>) -> ExitCode {
+    let jobs = jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        usize::from,
+    );
+    let start = Instant::now();
+    let outcomes = parallel::run(days::DAYS, parts, jobs);
+    let wall = start.elapsed();
+
+    print!("{}", report::outcome_table(&outcomes));
+    let failed = outcomes.iter().filter(|o| o.status.failed()).count();
+    println!(
+        "{failed} failed, {} on {jobs} threads",
+        format_duration(wall)
+    );
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Days without input only fail when `need_input` is set, like in text mode.
+fn run_json(
+    days: &[(&dyn Solution, PathBuf, io::Result<Vec<u8>>)],
+    parts: &[Part],
+    need_input: bool,
+) -> ExitCode {
+    let mut records = Vec::new();
+    let mut failed = false;
+
+    for (solution, path, input) in days {
+        let day = solution.day();
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                records.push(json::error(day, format!("{}: {e}", path.display())));
+                failed |= need_input;
+                continue;
+            }
+        };
+
+        match json::solve(*solution, parts, input) {
+            Ok(day_records) => records.extend(day_records),
+            Err(e) => {
+                records.push(json::error(day, format!("{}:{e}", path.display())));
+                failed = true;
+            }
+        }
+    }
+
+    println!("{}", serde_json::Value::Array(records));
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Multi-line answers start on a new line.
+fn inline(answer: &Answer) -> String {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        format!("\n{answer}")
+    } else {
+        answer
+    }
+}
+
+fn check(solutions: &[&dyn Solution], record: bool) -> ExitCode {
+    let (mut ok, mut new, mut failed, mut no_input) = (0, 0, 0, 0);
+
+    'days: for solution in solutions {
+        let day = solution.day();
+        let path = default_input(day);
+        let Ok(input) = std::fs::read(&path) else {
+            println!("Day {day:2}: no input");
+            no_input += 1;
+            continue;
+        };
+
+        let answers_path = answers::path(&path);
+        let mut answers = match Answers::load(&answers_path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}: {e}", answers_path.display());
+                failed += 1;
+                continue;
+            }
+        };
+
+        let mut changed = false;
+        for part in Part::ALL {
+            let answer = match solution.solve(part, &input) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("{}:{e}", path.display());
+                    failed += 1;
+                    continue 'days;
+                }
+            };
+            match answers.check(part, &answer) {
+                Verdict::Ok => {
+                    println!("Day {day:2} part {part}: ok");
+                    ok += 1;
+                }
+                Verdict::Mismatch { expected } => {
+                    println!(
+                        "Day {day:2} part {part}: MISMATCH, expected {} got {}",
+                        inline(expected),
+                        inline(&answer)
+                    );
+                    failed += 1;
+                }
+                Verdict::New => {
+                    println!("Day {day:2} part {part}: new answer {}", inline(&answer));
+                    new += 1;
+                    if record {
+                        answers.set(part, answer);
+                        changed = true;
+                    }
+                }
+                Verdict::Missing { expected } => {
+                    println!(
+                        "Day {day:2} part {part}: MISSING, expected {}",
+                        inline(expected)
+                    );
+                    failed += 1;
+                }
+                Verdict::Unsolved => println!("Day {day:2} part {part}: unsolved"),
+            }
+        }
+
+        if changed {
+            if let Err(e) = answers.save(&answers_path) {
+                eprintln!("{}: {e}", answers_path.display());
+                failed += 1;
+            }
+        }
+    }
+
+    println!();
+    println!("{ok} ok, {failed} failed, {new} new, {no_input} without input");
+    if new > 0 && !record {
+        println!("Run with --record to save the new answers.");
+    }
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn generate(
+    solution: &dyn Solution,
+    size: usize,
+    seed: Option<u64>,
+    out: Option<&Path>,
+) -> ExitCode {
+    let seed = seed.unwrap_or_else(|| {
+        let seed = RandomState::new().build_hasher().finish();
+        eprintln!("seed {seed}");
+        seed
+    });
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let Some(input) = solution.generate(&mut rng, size) else {
+        eprintln!("day {} has no generator", solution.day());
+        return ExitCode::FAILURE;
+    };
+
+    write_output(out, &input)
+}
+
+/// Writes `text` to `out`, or to stdout when there is no file.
+fn write_output(out: Option<&Path>, text: &str) -> ExitCode {
+    let written = match out {
+        Some(path) => std::fs::write(path, text).map_err(|e| format!("{}: {e}", path.display())),
+        None => std::io::stdout()
+            .write_all(text.as_bytes())
+            .map_err(|e| format!("stdout: {e}")),
+    };
+    match written {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn snapshot(
+    solution: &dyn Solution,
+    part: Part,
+    (path, input): (&Path, &[u8]),
+    steps: usize,
+    out: Option<&Path>,
+) -> ExitCode {
+    match solution.snapshot(part, input, steps) {
+        Ok(Some(json)) => write_output(out, &json),
+        Ok(None) => {
+            eprintln!("day {} has no snapshots", solution.day());
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}:{e}", path.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn resume(path: &Path) -> ExitCode {
+    let read = read_input_from(path)
+        .map_err(|e| e.to_string())
+        .and_then(|data| String::from_utf8(data).map_err(|_| "snapshot is not UTF-8".to_owned()));
+    let json = match read {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("{}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let header = match SnapshotHeader::read(&json) {
+        Ok(header) => header,
+        Err(e) => {
+            eprintln!("{}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(solution) = days::find(header.day) else {
+        eprintln!("day {} is not solved", header.day);
+        return ExitCode::FAILURE;
+    };
+
+    let start = Instant::now();
+    match solution.resume(&json) {
+        Ok(Some(answer)) => {
+            print_result(header.day, header.part, &answer, start.elapsed());
+            ExitCode::SUCCESS
+        }
+        Ok(None) => {
+            eprintln!("day {} has no snapshots", header.day);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}: {e}", path.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logger(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            animate,
+            delay,
+            frames,
+            no_color,
+            parallel,
+            jobs,
+            gif,
+            png,
+            scale,
+        } => {
+            let parts = parts(part);
+
+            if parallel {
+                return run_parallel(&parts, jobs);
+            }
+            let Some(day) = day else {
+                return match format {
+                    Format::Text => run_all(&parts),
+                    Format::Json => {
+                        let days: Vec<_> = days::DAYS
+                            .iter()
+                            .map(|&solution| {
+                                let path = default_input(solution.day());
+                                let input = std::fs::read(&path);
+                                (solution, path, input)
+                            })
+                            .collect();
+                        run_json(&days, &parts, false)
+                    }
+                };
+            };
+
+            let Some(solution) = days::find(day) else {
+                eprintln!("day {day} is not solved");
+                return ExitCode::FAILURE;
+            };
+
+            let path = input.unwrap_or_else(|| default_input(day));
+            let data = read_input_from(&path);
+            if let Format::Json = format {
+                return run_json(&[(solution, path, data)], &parts, true);
+            }
+            let data = match data {
+                Ok(data) => data,
+                Err(e) => {
+                    eprintln!("{}: {e}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let input = (path.as_path(), data.as_slice());
+
+            let scale = usize::from(scale);
+            if let Some(png) = png {
+                let code = write_png(solution, input, &png, scale);
+                if code != ExitCode::SUCCESS {
+                    return code;
+                }
+            }
+            if animate {
+                let delay = Duration::from_millis(delay);
+                if let Some(gif) = gif {
+                    return run_gif(solution, &parts, input, &gif, delay, scale);
+                }
+                return match frames {
+                    Some(frames) => match File::create(&frames) {
+                        Ok(file) => {
+                            let renderer = Renderer::headless(BufWriter::new(file));
+                            run_animated(solution, &parts, input, renderer)
+                        }
+                        Err(e) => {
+                            eprintln!("{}: {e}", frames.display());
+                            ExitCode::FAILURE
+                        }
+                    },
+                    None => {
+                        let renderer = Renderer::terminal(delay, !no_color);
+                        run_animated(solution, &parts, input, renderer)
+                    }
+                };
+            }
+            run_day(solution, &parts, input)
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => match days::find(day) {
+            Some(solution) => generate(solution, size, seed, output.as_deref()),
+            None => {
+                eprintln!("day {day} is not solved");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Snapshot {
+            day,
+            part,
+            input,
+            steps,
+            output,
+        } => {
+            let Some(solution) = days::find(day) else {
+                eprintln!("day {day} is not solved");
+                return ExitCode::FAILURE;
+            };
+            let path = input.unwrap_or_else(|| default_input(day));
+            let data = match read_input_from(&path) {
+                Ok(data) => data,
+                Err(e) => {
+                    eprintln!("{}: {e}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let part = parts(Some(part))[0];
+            snapshot(solution, part, (&path, &data), steps, output.as_deref())
+        }
+        Command::Resume { file } => resume(&file),
+        Command::Check { day, record } => match day {
+            None => check(days::DAYS, record),
+            Some(day) => match days::find(day) {
+                Some(solution) => check(&[solution], record),
+                None => {
+                    eprintln!("day {day} is not solved");
+                    ExitCode::FAILURE
+                }
+            },
+        },
+    }
+}
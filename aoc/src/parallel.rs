@@ -0,0 +1,226 @@
+use crate::answers::{self, Answers, Verdict};
+use aoc_common::{Answer, Part, Solution};
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
+
+/// How one part of one day went.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// Nothing recorded yet.
+    New,
+    Unsolved,
+    Mismatch(Answer),
+    /// Recorded, but the part has no solver anymore.
+    Missing(Answer),
+    NoInput,
+    /// The input or answers file did not load, the diagnostic went to stderr.
+    Failed(&'static str),
+    Panicked(String),
+}
+
+impl Status {
+    pub fn failed(&self) -> bool {
+        matches!(
+            self,
+            Self::Mismatch(_) | Self::Missing(_) | Self::Failed(_) | Self::Panicked(_)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+    pub status: Status,
+}
+
+/// A day with its input and recorded answers loaded.
+pub struct Day<'a> {
+    pub solution: &'a dyn Solution,
+    pub input: Vec<u8>,
+    pub answers: Answers,
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| (*s).to_owned())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+fn solve_part(day: &Day, part: Part) -> Outcome {
+    let outcome = |answer, time, status| Outcome {
+        day: day.solution.day(),
+        part,
+        answer,
+        time,
+        status,
+    };
+
+    let timed = panic::catch_unwind(AssertUnwindSafe(|| day.solution.timed(part, &day.input)));
+    let (answer, time) = match timed {
+        Ok((Ok(answer), time)) => (answer, time),
+        Ok((Err(e), _)) => {
+            eprintln!("{}:{e}", crate::default_input(day.solution.day()).display());
+            return outcome(
+                Answer::Unsolved,
+                Duration::ZERO,
+                Status::Failed("parse error"),
+            );
+        }
+        Err(payload) => {
+            let msg = panic_message(payload.as_ref());
+            return outcome(Answer::Unsolved, Duration::ZERO, Status::Panicked(msg));
+        }
+    };
+
+    let status = match day.answers.check(part, &answer) {
+        Verdict::Ok => Status::Ok,
+        Verdict::New => Status::New,
+        Verdict::Unsolved => Status::Unsolved,
+        Verdict::Mismatch { expected } => Status::Mismatch(expected.clone()),
+        Verdict::Missing { expected } => Status::Missing(expected.clone()),
+    };
+    outcome(answer, time, status)
+}
+
+/// Every part of every day, spread over `jobs` threads. A panic only fails
+/// the part that panicked.
+pub fn solve(days: &[Day], parts: &[Part], jobs: usize) -> Vec<Outcome> {
+    let tasks: Vec<(&Day, Part)> = days
+        .iter()
+        .flat_map(|day| parts.iter().map(move |&part| (day, part)))
+        .collect();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let tx = tx.clone();
+            let (tasks, next) = (&tasks, &next);
+            s.spawn(move || {
+                while let Some(&(day, part)) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    tx.send(solve_part(day, part)).unwrap();
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut outcomes: Vec<Outcome> = rx.into_iter().collect();
+    outcomes.sort_by_key(|o| (o.day, o.part as usize));
+    outcomes
+}
+
+/// [`solve`] on `dayN/input/input.txt` of every day that has one.
+pub fn run(solutions: &[&dyn Solution], parts: &[Part], jobs: usize) -> Vec<Outcome> {
+    let mut days = Vec::with_capacity(solutions.len());
+    let mut outcomes = Vec::new();
+    let mut skip = |day, status: fn() -> Status| {
+        outcomes.extend(parts.iter().map(|&part| Outcome {
+            day,
+            part,
+            answer: Answer::Unsolved,
+            time: Duration::ZERO,
+            status: status(),
+        }));
+    };
+
+    for &solution in solutions {
+        let day = solution.day();
+        let path = crate::default_input(day);
+        let Ok(input) = std::fs::read(&path) else {
+            skip(day, || Status::NoInput);
+            continue;
+        };
+        let answers_path = answers::path(&path);
+        let answers = match Answers::load(&answers_path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}: {e}", answers_path.display());
+                skip(day, || Status::Failed("bad answers file"));
+                continue;
+            }
+        };
+        days.push(Day {
+            solution,
+            input,
+            answers,
+        });
+    }
+
+    outcomes.extend(solve(&days, parts, jobs));
+    outcomes.sort_by_key(|o| (o.day, o.part as usize));
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, Day, Status};
+    use crate::answers::Answers;
+    use aoc_common::{Answer, ParseError, Part, Solution};
+
+    struct Panics;
+
+    impl Solution for Panics {
+        fn day(&self) -> u8 {
+            99
+        }
+
+        fn parse(&self, _input: &[u8]) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &[u8]) -> Result<Answer, ParseError> {
+            panic!("no way");
+        }
+
+        fn part2(&self, _input: &[u8]) -> Result<Answer, ParseError> {
+            Ok(Answer::Num(7))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut answers = Answers::default();
+        answers.set(Part::Two, Answer::Num(45_000));
+        let days = [
+            Day {
+                solution: &Panics,
+                input: Vec::new(),
+                answers: Answers::default(),
+            },
+            Day {
+                solution: &day1::Solver,
+                input: b"1000\n\n2000\n".to_vec(),
+                answers,
+            },
+        ];
+
+        let outcomes = solve(&days, &Part::ALL, 4);
+        let statuses: Vec<_> = outcomes.iter().map(|o| (o.day, &o.status)).collect();
+        assert_eq!(
+            statuses,
+            [
+                (1, &Status::New),
+                (1, &Status::Mismatch(Answer::Num(45_000))),
+                (99, &Status::Panicked("no way".to_owned())),
+                (99, &Status::New),
+            ]
+        );
+        assert_eq!(outcomes[1].answer, Answer::Num(3000));
+        assert!(outcomes.iter().any(|o| o.status.failed()));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::parallel::{Outcome, Status};
+use aoc_common::{format_duration, Answer};
+use std::time::Duration;
+
+/// Outcome of one day in a run-all.
+pub enum Row {
+    Solved {
+        day: u8,
+        answers: [Answer; 2],
+        time: Duration,
+    },
+    NoInput {
+        day: u8,
+    },
+    /// The input did not parse, the diagnostic went to stderr.
+    Failed {
+        day: u8,
+    },
+}
+
+fn cell(answer: &Answer) -> String {
+    let s = answer.to_string();
+    if s.contains('\n') {
+        "<multi-line>".to_owned()
+    } else {
+        s
+    }
+}
+
+pub fn table(rows: &[Row]) -> String {
+    let cells: Vec<(u8, String, String, String)> = rows
+        .iter()
+        .map(|row| match row {
+            Row::Solved { day, answers, time } => (
+                *day,
+                cell(&answers[0]),
+                cell(&answers[1]),
+                format_duration(*time),
+            ),
+            Row::NoInput { day } => (*day, "no input".to_owned(), String::new(), String::new()),
+            Row::Failed { day } => (*day, "parse error".to_owned(), String::new(), String::new()),
+        })
+        .collect();
+
+    let w1 = cells.iter().map(|c| c.1.len()).max().unwrap_or(0).max(6);
+    let w2 = cells.iter().map(|c| c.2.len()).max().unwrap_or(0).max(6);
+    let w3 = cells.iter().map(|c| c.3.len()).max().unwrap_or(0).max(4);
+
+    let mut out = format!("Day | {:w1$} | {:w2$} | {:>w3$}\n", "Part 1", "Part 2", "Time");
+    out += &format!("----+-{}-+-{}-+-{}\n", "-".repeat(w1), "-".repeat(w2), "-".repeat(w3));
+    for (day, p1, p2, time) in cells {
+        let line = format!("{day:3} | {p1:w1$} | {p2:w2$} | {time:>w3$}");
+        out += line.trim_end();
+        out.push('\n');
+    }
+    out
+}
+
+fn status(status: &Status) -> String {
+    match status {
+        Status::Ok => "ok".to_owned(),
+        Status::New => "new".to_owned(),
+        Status::Unsolved => "unsolved".to_owned(),
+        Status::Mismatch(expected) => format!("MISMATCH, expected {}", cell(expected)),
+        Status::Missing(expected) => format!("MISSING, expected {}", cell(expected)),
+        Status::NoInput => "no input".to_owned(),
+        Status::Failed(msg) => (*msg).to_owned(),
+        Status::Panicked(msg) => format!("PANIC: {}", msg.lines().next().unwrap_or("")),
+    }
+}
+
+/// One line per part, for the parallel run.
+pub fn outcome_table(outcomes: &[Outcome]) -> String {
+    let cells: Vec<(u8, String, String, String, String)> = outcomes
+        .iter()
+        .map(|o| {
+            let ran = !matches!(
+                o.status,
+                Status::NoInput | Status::Failed(_) | Status::Panicked(_)
+            );
+            let (answer, time) = if ran {
+                (cell(&o.answer), format_duration(o.time))
+            } else {
+                (String::new(), String::new())
+            };
+            (o.day, o.part.to_string(), answer, time, status(&o.status))
+        })
+        .collect();
+
+    let w1 = cells.iter().map(|c| c.2.len()).max().unwrap_or(0).max(6);
+    let w2 = cells.iter().map(|c| c.3.len()).max().unwrap_or(0).max(4);
+
+    let mut out = format!("Day | Part | {:w1$} | {:>w2$} | Status\n", "Answer", "Time");
+    out += &format!(
+        "----+------+-{}-+-{}-+-------\n",
+        "-".repeat(w1),
+        "-".repeat(w2)
+    );
+    for (day, part, answer, time, status) in cells {
+        out += &format!("{day:3} | {part:>4} | {answer:w1$} | {time:>w2$} | {status}\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{outcome_table, table, Row};
+    use crate::parallel::{Outcome, Status};
+    use aoc_common::{Answer, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn test_table() {
+        let rows = [
+            Row::Solved {
+                day: 1,
+                answers: [Answer::Num(24000), Answer::Num(45000)],
+                time: Duration::from_micros(153),
+            },
+            Row::Solved {
+                day: 10,
+                answers: [Answer::Num(13140), Answer::Text("##..\n..##".to_owned())],
+                time: Duration::from_micros(20),
+            },
+            Row::Failed { day: 24 },
+            Row::NoInput { day: 25 },
+        ];
+
+        assert_eq!(
+            table(&rows),
+            "Day | Part 1      | Part 2       |   Time
+----+-------------+--------------+-------
+  1 | 24000       | 45000        | 153 uS
+ 10 | 13140       | <multi-line> |  20 uS
+ 24 | parse error |              |
+ 25 | no input    |              |
+"
+        );
+    }
+
+    #[test]
+    fn test_outcome_table() {
+        let outcome = |day, part, answer, status| Outcome {
+            day,
+            part,
+            answer,
+            time: Duration::from_micros(153),
+            status,
+        };
+        let outcomes = [
+            outcome(1, Part::One, Answer::Num(24000), Status::Ok),
+            outcome(
+                1,
+                Part::Two,
+                Answer::Num(45001),
+                Status::Mismatch(Answer::Num(45000)),
+            ),
+            outcome(
+                9,
+                Part::One,
+                Answer::Unsolved,
+                Status::Panicked("index out of bounds".to_owned()),
+            ),
+            outcome(25, Part::One, Answer::Unsolved, Status::NoInput),
+        ];
+
+        assert_eq!(
+            outcome_table(&outcomes),
+            "Day | Part | Answer |   Time | Status
+----+------+--------+--------+-------
+  1 |    1 | 24000  | 153 uS | ok
+  1 |    2 | 45001  | 153 uS | MISMATCH, expected 45000
+  9 |    1 |        |        | PANIC: index out of bounds
+ 25 |    1 |        |        | no input
+"
+        );
+    }
+}
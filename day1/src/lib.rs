@@ -0,0 +1,417 @@
+use aoc_common::{lines, try_slice_to_number, Answer, ParseError, Solution};
+use rand::{Rng, RngCore};
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display, Write},
+    io::{self, BufRead},
+};
+
+/// An elf, numbered from 0 in the order of the list, and what it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// The `K` elves with the most calories, most first. Elves with the same
+/// total keep the order of the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct High<const K: usize>(Vec<Elf>);
+
+impl<const K: usize> High<K> {
+    fn new() -> Self {
+        Self(Vec::with_capacity(K + 1))
+    }
+
+    fn insert(&mut self, elf: Elf) {
+        let pos = self.0.partition_point(|e| e.calories >= elf.calories);
+        if pos < K {
+            self.0.insert(pos, elf);
+            self.0.truncate(K);
+        }
+    }
+
+    /// Fewer than `K` when the list has fewer elves.
+    pub fn elves(&self) -> &[Elf] {
+        &self.0
+    }
+
+    /// `None` when the total does not fit in a `u64`.
+    pub fn sum(&self) -> Option<u64> {
+        self.0
+            .iter()
+            .try_fold(0u64, |sum, e| sum.checked_add(e.calories))
+    }
+}
+
+/// Why a calorie list could not be read.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => e.fmt(f),
+            Self::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// The `K` elves that carry the most, read a line at a time so the list does
+/// not have to fit in memory.
+///
+/// # Errors
+/// When reading fails, or on a line that is neither calories nor blank.
+pub fn top<const K: usize>(mut input: impl BufRead) -> Result<High<K>, ReadError> {
+    let mut high = High::new();
+    let mut elf = Elf {
+        index: 0,
+        calories: 0,
+    };
+    // Blank lines only count once more calories follow, trailing ones do not
+    // add empty elves.
+    let mut blanks = 0;
+    let mut buf = Vec::new();
+
+    for line_no in 1.. {
+        buf.clear();
+        if input.read_until(b'\n', &mut buf).map_err(ReadError::Io)? == 0 {
+            break;
+        }
+        let line = buf.strip_suffix(b"\n").unwrap_or(&buf);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        if line.is_empty() {
+            blanks += 1;
+            continue;
+        }
+        for _ in 0..blanks {
+            high.insert(elf);
+            elf = Elf {
+                index: elf.index + 1,
+                calories: 0,
+            };
+        }
+        blanks = 0;
+
+        let err = |msg| {
+            let mut e = ParseError::at(line, 0, msg);
+            e.line = line_no;
+            ReadError::Parse(e)
+        };
+        let calories = try_slice_to_number::<u64>(line).ok_or_else(|| err("expected calories"))?;
+        elf.calories = elf
+            .calories
+            .checked_add(calories)
+            .ok_or_else(|| err("too many calories for one elf"))?;
+    }
+    high.insert(elf);
+
+    Ok(high)
+}
+
+/// The three elves that carry the most.
+pub fn parse(input: &[u8]) -> Result<High<3>, ParseError> {
+    top(input).map_err(|e| match e {
+        ReadError::Parse(e) => e,
+        ReadError::Io(e) => ParseError::at(input, 0, e.to_string()),
+    })
+}
+
+pub fn part1(high: &High<3>) -> u64 {
+    high.elves().first().map_or(0, |e| e.calories)
+}
+
+/// `None` when the three together carry more than fits in a `u64`.
+pub fn part2(high: &High<3>) -> Option<u64> {
+    high.sum()
+}
+
+/// Totals past `i64::MAX` have no [`Answer`].
+fn answer(calories: Option<u64>) -> Answer {
+    calories
+        .and_then(|c| i64::try_from(c).ok())
+        .map_or(Answer::Unsolved, Answer::Num)
+}
+
+/// The calories of each snack, one list per elf. Every blank line starts the
+/// next elf.
+///
+/// # Errors
+/// On a line that is neither calories nor blank, or when an elf carries more
+/// than fits in a `u64`.
+pub fn inventories(input: &[u8]) -> Result<Vec<Vec<u64>>, ParseError> {
+    let mut elves = vec![Vec::new()];
+    let mut total = 0u64;
+
+    for line in lines(input) {
+        if line.is_empty() {
+            elves.push(Vec::new());
+            total = 0;
+            continue;
+        }
+        let calories = try_slice_to_number::<u64>(line)
+            .ok_or_else(|| ParseError::at_slice(input, line, "expected calories"))?;
+        total = total
+            .checked_add(calories)
+            .ok_or_else(|| ParseError::at_slice(input, line, "too many calories for one elf"))?;
+        elves.last_mut().unwrap().push(calories);
+    }
+
+    Ok(elves)
+}
+
+/// What one elf carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub index: usize,
+    pub count: usize,
+    pub sum: u64,
+    /// `None` for an elf without snacks.
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
+impl Stats {
+    fn new(index: usize, snacks: &[u64]) -> Option<Self> {
+        Some(Self {
+            index,
+            count: snacks.len(),
+            sum: snacks.iter().try_fold(0u64, |sum, &c| sum.checked_add(c))?,
+            min: snacks.iter().copied().min(),
+            max: snacks.iter().copied().max(),
+        })
+    }
+}
+
+/// `None` when an elf carries more than fits in a `u64`, which
+/// [`inventories`] already rejects.
+pub fn stats(inventories: &[Vec<u64>]) -> Option<Vec<Stats>> {
+    inventories
+        .iter()
+        .enumerate()
+        .map(|(index, snacks)| Stats::new(index, snacks))
+        .collect()
+}
+
+/// Every elf, most calories first, printed as a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking(Vec<Stats>);
+
+impl Ranking {
+    /// `None` like [`stats`].
+    pub fn new(inventories: &[Vec<u64>]) -> Option<Self> {
+        let mut stats = stats(inventories)?;
+        stats.sort_by_key(|s| Reverse(s.sum));
+        Some(Self(stats))
+    }
+
+    pub fn elves(&self) -> &[Stats] {
+        &self.0
+    }
+}
+
+impl Display for Ranking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let opt = |v: Option<u64>| v.map_or_else(|| "-".to_owned(), |v| v.to_string());
+
+        writeln!(
+            f,
+            "{:>4} {:>5} {:>6} {:>8} {:>8} {:>8}",
+            "rank", "elf", "snacks", "total", "min", "max"
+        )?;
+        for (rank, elf) in self.0.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4} {:>5} {:>6} {:>8} {:>8} {:>8}",
+                rank + 1,
+                elf.index,
+                elf.count,
+                elf.sum,
+                opt(elf.min),
+                opt(elf.max)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Snack lists of `size` elves.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut out = String::new();
+    for elf in 0..size.max(1) {
+        if elf > 0 {
+            out.push('\n');
+        }
+        for _ in 0..rng.random_range(1..=15) {
+            writeln!(out, "{}", rng.random_range(1000..=70_000)).unwrap();
+        }
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(answer(Some(part1(&parse(input)?))))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(answer(part2(&parse(input)?)))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        generate, inventories, parse, part1, part2, stats, top, Elf, Ranking, ReadError, Solver,
+    };
+    use aoc_common::{Answer, Solution};
+    use std::io::{self, BufReader, Read};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(&parse(INPUT).unwrap()), 24000);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()), Some(45000));
+    }
+
+    #[test]
+    fn test_top() {
+        let elf = |index, calories| Elf { index, calories };
+
+        let high = top::<2>(BufReader::with_capacity(4, INPUT)).unwrap();
+        assert_eq!(high.elves(), [elf(3, 24000), elf(2, 11000)]);
+        let high = top::<10>(INPUT).unwrap();
+        assert_eq!(high.elves().len(), 5);
+        assert_eq!(high.elves()[4], elf(1, 4000));
+        assert_eq!(high.sum(), Some(55000));
+        assert!(top::<0>(INPUT).unwrap().elves().is_empty());
+
+        // Ties keep the list order, trailing blank lines add no elves.
+        let high = top::<3>(&b"5\r\n\r\n\r\n5\r\n\r\n"[..]).unwrap();
+        assert_eq!(high.elves(), [elf(0, 5), elf(2, 5), elf(1, 0)]);
+        assert_eq!(top::<3>(&b""[..]).unwrap().elves(), [elf(0, 0)]);
+
+        // Past what fits in a u32.
+        let big = "4000000000\n4000000000\n\n1\n";
+        assert_eq!(part1(&parse(big.as_bytes()).unwrap()), 8_000_000_000);
+
+        // Past what fits in an i64 or, all three together, in a u64.
+        let huge = "10000000000000000000\n";
+        assert_eq!(Solver.part1(huge.as_bytes()), Ok(Answer::Unsolved));
+        let huge = huge.repeat(3).replace('\n', "\n\n");
+        assert_eq!(part2(&parse(huge.as_bytes()).unwrap()), None);
+        assert_eq!(Solver.part2(huge.as_bytes()), Ok(Answer::Unsolved));
+
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+        let e = top::<3>(BufReader::new(Broken)).unwrap_err();
+        assert!(matches!(e, ReadError::Io(_)));
+        assert_eq!(e.to_string(), "disk on fire");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(b"1000\n2x00\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "2x00"));
+        let e = parse(b"10000000000000000000\n10000000000000000000\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.msg.as_str()),
+            (2, "too many calories for one elf")
+        );
+    }
+
+    #[test]
+    fn test_inventories() {
+        let elves = inventories(INPUT).unwrap();
+        assert_eq!(elves.len(), 5);
+        assert_eq!(elves[1], [4000]);
+        assert_eq!(elves[3], [7000, 8000, 9000]);
+        assert_eq!(
+            inventories(b"1\n\n\n2\n\n").unwrap(),
+            [vec![1], vec![], vec![2]]
+        );
+
+        // A typo is not a separator.
+        let e = inventories(b"1000\n\n12a0\n3000\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "12a0"));
+        assert_eq!(e.msg, "expected calories");
+        let e = inventories(b"1\n \n2\n").unwrap_err();
+        assert_eq!(e.line, 2);
+        let e = inventories(b"10000000000000000000\n10000000000000000000\n").unwrap_err();
+        assert_eq!(e.msg, "too many calories for one elf");
+    }
+
+    #[test]
+    fn test_ranking() {
+        let ranking = Ranking::new(&inventories(b"5\n1\n\n\n6\n").unwrap()).unwrap();
+        let top = ranking.elves()[0];
+        assert_eq!((top.index, top.count, top.sum), (0, 2, 6));
+        assert_eq!((top.min, top.max), (Some(1), Some(5)));
+        assert_eq!(ranking.elves()[1].index, 2);
+        assert_eq!(
+            (ranking.elves()[2].count, ranking.elves()[2].min),
+            (0, None)
+        );
+        assert_eq!(
+            ranking.to_string(),
+            "rank   elf snacks    total      min      max\n   \
+             1     0      2        6        1        5\n   \
+             2     2      1        6        6        6\n   \
+             3     1      0        0        -        -\n"
+        );
+
+        let ranking = Ranking::new(&inventories(INPUT).unwrap()).unwrap();
+        let sums: Vec<_> = ranking.elves().iter().map(|e| (e.index, e.sum)).collect();
+        assert_eq!(
+            sums,
+            [(3, 24000), (2, 11000), (4, 10000), (0, 6000), (1, 4000)]
+        );
+
+        let huge = vec![vec![u64::MAX, 1], vec![2]];
+        assert_eq!(stats(&huge), None);
+        assert_eq!(Ranking::new(&huge), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(1);
+        for size in [1, 3, 100] {
+            let input = generate(&mut rng, size);
+            let high = top::<100>(input.as_bytes()).unwrap();
+            assert_eq!(high.elves().len(), size);
+            let high = parse(input.as_bytes()).unwrap();
+            assert!(Some(part1(&high)) <= part2(&high));
+        }
+    }
+}
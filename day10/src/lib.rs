@@ -0,0 +1,303 @@
+#![deny(clippy::pedantic)]
+
+use aoc_common::{lines, Answer, Grid, Image, Palette, ParseError, Solution};
+use core::{fmt::Write, str::from_utf8};
+use rand::{Rng, RngCore};
+
+pub type Signal = i16;
+
+pub type X = i8;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Op {
+    Noop,
+
+    Addx(X),
+}
+
+struct VideoSystem {
+    cycle: u8,
+
+    x: X,
+
+    crt: [u8; 240],
+}
+
+impl VideoSystem {
+    const WIDTH: usize = 40;
+
+    fn new() -> Self {
+        Self {
+            cycle: 0,
+
+            x: 1,
+
+            crt: [b' '; 240],
+        }
+    }
+
+    fn cycle_check(&self) -> Option<Signal> {
+        if [20_u8, 60, 100, 140, 180, 220].contains(&self.cycle) {
+            return Some(Signal::from(self.cycle) * Signal::from(self.x));
+        }
+
+        None
+    }
+
+    fn draw_pixel(&mut self) {
+        let mem = usize::from(self.cycle - 1);
+
+        if mem < self.crt.len() {
+            let x = mem % Self::WIDTH;
+
+            self.crt[mem] = if X::try_from(x).unwrap().abs_diff(self.x) <= 1 {
+                b'#'
+            } else {
+                b'.'
+            };
+        }
+    }
+
+    fn screen(&self) -> String {
+        self.crt
+            .chunks_exact(Self::WIDTH)
+            .map(|line| from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The CRT, lit pixels green.
+    fn image(&self) -> Image {
+        let palette = Palette::new([10, 10, 10]).with(b'#', [60, 220, 60]);
+        let crt = Grid::from_vec(Self::WIDTH, self.crt.to_vec());
+        Image::from_grid(&crt, |c| palette.color(c))
+    }
+
+    fn clock_tick(&mut self) {
+        self.cycle += 1;
+
+        self.draw_pixel();
+    }
+
+    fn instruction(&mut self, state: &Op) -> Option<Signal> {
+        self.clock_tick();
+
+        let mut signal = self.cycle_check();
+
+        match *state {
+            Op::Noop => (),
+
+            Op::Addx(v) => {
+                self.clock_tick();
+
+                if signal.is_none() {
+                    signal = self.cycle_check();
+                }
+
+                self.x += v;
+            }
+        }
+
+        signal
+    }
+}
+
+fn decode(input: &[u8], instr: &[u8]) -> Result<Op, ParseError> {
+    // println!("{}", from_utf8(instr).unwrap());
+
+    if instr == b"noop" {
+        return Ok(Op::Noop);
+    }
+
+    if let Some(v) = instr.strip_prefix(b"addx ") {
+        return from_utf8(v)
+            .ok()
+            .and_then(|v| v.parse::<X>().ok())
+            .map(Op::Addx)
+            .ok_or_else(|| ParseError::at_slice(input, v, "expected a number"));
+    }
+
+    Err(ParseError::at_slice(input, instr, "unknown instruction"))
+}
+
+/// Program up to the first empty line.
+///
+/// # Errors
+///
+/// On an unknown instruction or a bad `addx` operand.
+pub fn parse(input: &[u8]) -> Result<Vec<Op>, ParseError> {
+    lines(input)
+        .take_while(|line| !line.is_empty())
+        .map(|line| decode(input, line))
+        .collect()
+}
+
+#[must_use]
+pub fn part1(program: &[Op]) -> Signal {
+    let (signal, _) = run(program);
+
+    signal
+}
+
+#[must_use]
+pub fn part2(program: &[Op]) -> String {
+    let (_, vs) = run(program);
+
+    vs.screen()
+}
+
+fn run(program: &[Op]) -> (Signal, VideoSystem) {
+    let mut vs = VideoSystem::new();
+
+    let mut signal = 0;
+
+    for instr in program {
+        if let Some(v) = vs.instruction(instr) {
+            signal += v;
+        }
+    }
+
+    (signal, vs)
+}
+
+/// A program that runs for the 240 cycles of the screen and keeps the sprite
+/// close to it. Its length depends on the mix of instructions, not on `size`.
+#[must_use]
+pub fn generate(rng: &mut dyn RngCore, _size: usize) -> String {
+    let mut out = String::new();
+    let (mut cycles, mut x) = (0, 1);
+    while cycles < 240 {
+        if rng.random_bool(0.3) {
+            out.push_str("noop\n");
+            cycles += 1;
+        } else {
+            let v = loop {
+                let v: X = rng.random_range(-10..=10);
+                if v != 0 && (-1..=40).contains(&(x + v)) {
+                    break v;
+                }
+            };
+            writeln!(out, "addx {v}").unwrap();
+            x += v;
+            cycles += 2;
+        }
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn image(&self, input: &[u8]) -> Result<Option<Image>, ParseError> {
+        let (_, vs) = run(&parse(input)?);
+        Ok(Some(vs.image()))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Op;
+
+    use super::{
+        decode, lines, parse, part1,
+        Op::{Addx, Noop},
+        VideoSystem,
+    };
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    const CRT_ANS: &[u8] = b"##..##..##..##..##..##..##..##..##..##..###...###...###...###...###...###...###.####....####....####....####....####....#####.....#####.....#####.....#####.....######......######......######......###########.......#######.......#######.....";
+
+    #[test]
+    fn test_vecvec_new() {
+        let mut cpu = VideoSystem::new();
+
+        assert_eq!(cpu.instruction(&Noop), None);
+
+        assert_eq!(cpu.instruction(&Addx(3)), None);
+
+        assert_eq!(cpu.x, 4);
+
+        assert_eq!(cpu.instruction(&Addx(-5)), None);
+
+        assert_eq!(cpu.x, -1);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(b"noop", b"noop").unwrap(), Op::Noop);
+
+        assert_eq!(decode(b"addx 0", b"addx 0").unwrap(), Op::Addx(0));
+
+        assert_eq!(decode(b"addx -15", b"addx -15").unwrap(), Op::Addx(-15));
+
+        assert_eq!(decode(b"addx 1", b"addx 1").unwrap(), Op::Addx(1));
+
+        let e = parse(b"noop\naddx x\nnoop").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 6, "x"));
+        let e = parse(b"noop\nmul 2").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (2, "unknown instruction"));
+    }
+
+    #[test]
+    fn test_example() {
+        let mut cpu = VideoSystem::new();
+
+        let mut signal = 0;
+
+        for line in lines(INPUT) {
+            let instr = decode(INPUT, line).unwrap();
+
+            if let Some(v) = cpu.instruction(&instr) {
+                signal += v;
+            }
+        }
+
+        assert_eq!(signal, 13140);
+
+        println!("{}", cpu.screen());
+
+        assert_eq!(&cpu.crt, CRT_ANS);
+
+        let image = cpu.image();
+        assert_eq!((image.width(), image.height()), (40, 6));
+        assert_eq!(image.get(0, 0), Some([60, 220, 60]));
+        assert_eq!(image.get(2, 0), Some([10, 10, 10]));
+
+        assert_eq!(part1(&parse(INPUT).unwrap()), 13140);
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(10);
+        for _ in 0..20 {
+            let program = parse(generate(&mut rng, 0).as_bytes()).unwrap();
+            assert!(part1(&program) >= -720);
+            assert!(!super::part2(&program).contains(' '));
+        }
+    }
+}
@@ -0,0 +1,560 @@
+use aoc_common::{as_str, str_lines, Answer, ParseError, Part, Snapshot, Solution};
+use log::debug;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Write, ops::Range};
+
+type Item = u64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Operations {
+    Add(Item),
+    MulOld,
+    Mul(Item),
+}
+
+impl Operations {
+    /// New worry level, `None` when it does not fit in an [`Item`].
+    fn checked(&self, item: Item) -> Option<Item> {
+        match self {
+            Self::Add(n) => item.checked_add(*n),
+            Self::MulOld => item.checked_mul(item),
+            Self::Mul(n) => item.checked_mul(*n),
+        }
+    }
+
+    /// New worry level modulo `m`.
+    fn modulo(&self, item: Item, m: Item) -> Item {
+        let (item, m) = (u128::from(item), u128::from(m));
+        let level = match self {
+            Self::Add(n) => item + u128::from(*n),
+            Self::MulOld => item * item,
+            Self::Mul(n) => item * u128::from(*n),
+        };
+        (level % m) as Item
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Monkey {
+    items: Vec<Item>,
+    operation: Operations,
+    test: Item,
+    throw: [usize; 2],
+    inspected: usize,
+}
+
+impl Monkey {
+    fn new() -> Self {
+        Self {
+            items: Vec::with_capacity(10),
+            operation: Operations::MulOld,
+            test: 0,
+            throw: [0, 0],
+            inspected: 0,
+        }
+    }
+}
+
+impl Default for Monkey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day11(Vec<Monkey>);
+
+fn round_count(part: Part) -> usize {
+    match part {
+        Part::One => 20,
+        Part::Two => 10_000,
+    }
+}
+
+impl Day11 {
+    // Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let err =
+            |part: &str, msg: &str| ParseError::at_slice(input.as_bytes(), part.as_bytes(), msg);
+
+        let mut monkeys = Vec::with_capacity(7);
+        let mut targets = Vec::with_capacity(14);
+        let mut divisors: Item = 1;
+
+        let mut monkey = Monkey::default();
+
+        for line in str_lines(input) {
+            let line = line.trim();
+            //println!("{line}");
+            if line.starts_with("Monkey ") {
+                monkey = Monkey::default();
+                continue;
+            }
+
+            let mut words = line.split(" ");
+            let mut word = |n| {
+                words
+                    .nth(n)
+                    .ok_or_else(|| err(&line[line.len()..], "line ends too early"))
+            };
+            let number = |w: &str| w.parse::<Item>().map_err(|_| err(w, "expected a number"));
+
+            if line.starts_with("Starting items: ") {
+                monkey.items = words
+                    .skip(2)
+                    .map(|w| number(w.trim_end_matches(',')))
+                    .collect::<Result<_, _>>()?;
+            } else if line.starts_with("Operation: new = old ") {
+                let sign = word(4)?;
+                let n = word(0)?;
+                monkey.operation = match (sign, n) {
+                    ("*", "old") => Operations::MulOld,
+                    ("*", n) => Operations::Mul(number(n)?),
+                    ("+", n) => Operations::Add(number(n)?),
+                    (a, _) => return Err(err(a, "expected `*` or `+`")),
+                }
+            } else if line.starts_with("Test: divisible by") {
+                let w = word(3)?;
+                monkey.test = number(w)?;
+                if monkey.test == 0 {
+                    return Err(err(w, "divisor can't be zero"));
+                }
+                divisors = divisors
+                    .checked_mul(monkey.test)
+                    .ok_or_else(|| err(w, "divisors multiply past 64 bits"))?;
+            } else if line.starts_with("If true: throw to monkey") {
+                let w = word(5)?;
+                monkey.throw[0] = number(w)? as usize;
+                targets.push(w);
+            } else if line.starts_with("If false: throw to monkey") {
+                let w = word(5)?;
+                monkey.throw[1] = number(w)? as usize;
+                targets.push(w);
+                monkeys.push(monkey.clone());
+            } else if !line.is_empty() {
+                return Err(err(line, "unexpected line"));
+            }
+        }
+
+        if monkeys.len() < 2 {
+            return Err(err(&input[input.len()..], "expected at least two monkeys"));
+        }
+        if let Some(w) = targets
+            .iter()
+            .find(|w| w.parse::<usize>().unwrap() >= monkeys.len())
+        {
+            return Err(err(w, "no such monkey"));
+        }
+        //println!("{monkeys:#?}");
+        Ok(Self(monkeys))
+    }
+
+    /// A round of part 1, `None` when a worry level does not fit in an
+    /// [`Item`].
+    fn next(&mut self) -> Option<()> {
+        for m in 0..self.0.len() {
+            //println!("Monkey {m}:");
+            let monkey = self.0.get(m).unwrap().clone();
+
+            self.0[m].items.clear();
+
+            self.0[m].inspected += monkey.items.len();
+
+            for item in monkey.items {
+                //println!("  Monkey inspects an item with a worry level of {item}.");
+                let mut level = monkey.operation.checked(item)?;
+                level /= 3;
+                // println!(
+                //     "    Monkey gets bored with item. Worry level is divided by 3 to {level}."
+                // );
+
+                let other = if level % monkey.test == 0 {
+                    //println!("    Current worry level is not divisible by {}.", monkey.test);
+                    monkey.throw[0]
+                } else {
+                    //println!("    Current worry level is divisible by {}.", monkey.test);
+                    monkey.throw[1]
+                };
+                //println!("    Item with worry level {level} is thrown to monkey {other}.");
+                self.0[other].items.push(level);
+            }
+        }
+        Some(())
+    }
+
+
+    fn next_part2(&mut self, lcm: Item) {
+        
+        for m in 0..self.0.len() {
+            //println!("Monkey {m}:");
+            let monkey = self.0.get(m).unwrap().clone();
+
+            self.0[m].items.clear();
+
+            self.0[m].inspected += monkey.items.len();
+
+            for item in monkey.items {
+                //println!("  Monkey inspects an item with a worry level of {item}.");
+                let level = monkey.operation.modulo(item, lcm);
+                // println!(
+                //     "    Monkey gets bored with item. Worry level is divided by 3 to {level}."
+                // );
+
+                let other = if level % monkey.test == 0 {
+                    //println!("    Current worry level is not divisible by {}.", monkey.test);
+                    monkey.throw[0]
+                } else {
+                    //println!("    Current worry level is divisible by {}.", monkey.test);
+                    monkey.throw[1]
+                };
+                //println!("    Item with worry level {level} is thrown to monkey {other}.");
+                self.0[other].items.push(level);
+            }
+        }
+    }
+
+    /// Product of the divisors, part 2 keeps the worry levels modulo it.
+    fn lcm(&self) -> Item {
+        let lcm = self.0.iter().map(|n| n.test).product::<Item>();
+        debug!("lcm {lcm}");
+        lcm
+    }
+
+    fn round(&mut self, part: Part, lcm: Item) -> Option<()> {
+        match part {
+            Part::One => self.next(),
+            Part::Two => {
+                self.next_part2(lcm);
+                Some(())
+            }
+        }
+    }
+
+    /// Plays `rounds` of `part`, `None` when a worry level of part 1 no longer
+    /// fits in an [`Item`].
+    fn play(&mut self, part: Part, rounds: Range<usize>) -> Option<()> {
+        let lcm = self.lcm();
+        for _ in rounds {
+            self.round(part, lcm)?;
+        }
+        Some(())
+    }
+
+    /// Rounds of `part` played on these monkeys, until a worry level of part 1
+    /// no longer fits.
+    pub fn rounds(&mut self, part: Part) -> Rounds<'_> {
+        Rounds {
+            lcm: self.lcm(),
+            monkeys: self,
+            part,
+        }
+    }
+
+    /// Throws that go to a monkey that is not there, or a zero divisor, would
+    /// panic, so a hand-edited snapshot gets checked.
+    fn check(&self) -> Result<(), String> {
+        for (idx, m) in self.0.iter().enumerate() {
+            if m.test == 0 || m.throw.iter().any(|&t| t >= self.0.len()) {
+                return Err(format!("monkey {idx} can't be played"));
+            }
+        }
+        if self.0.len() < 2 {
+            return Err("expected at least two monkeys".to_owned());
+        }
+        let product = self
+            .0
+            .iter()
+            .try_fold(1, |p: Item, m| p.checked_mul(m.test));
+        if product.is_none() {
+            return Err("divisors multiply past 64 bits".to_owned());
+        }
+        Ok(())
+    }
+
+    fn end(&self) -> usize {
+        let mut hs: Vec<usize> = self.0.iter().map(|m| m.inspected).collect();
+
+        for (idx, times) in hs.iter().enumerate() {
+            debug!("Monkey {idx} inspected items {} times.", times);
+        }
+        
+        hs.sort();
+
+        hs[hs.len()-1] * hs[hs.len()-2]
+    }
+}
+
+/// The monkeys after a round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    /// Worry levels of the items each monkey holds.
+    pub items: Vec<Vec<Item>>,
+    /// Items each monkey inspected so far.
+    pub inspected: Vec<usize>,
+}
+
+/// See [`Day11::rounds`].
+pub struct Rounds<'a> {
+    monkeys: &'a mut Day11,
+    part: Part,
+    lcm: Item,
+}
+
+impl Iterator for Rounds<'_> {
+    type Item = Round;
+
+    fn next(&mut self) -> Option<Round> {
+        self.monkeys.round(self.part, self.lcm)?;
+        let monkeys = &self.monkeys.0;
+        Some(Round {
+            items: monkeys.iter().map(|m| m.items.clone()).collect(),
+            inspected: monkeys.iter().map(|m| m.inspected).collect(),
+        })
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    /// Steps are rounds. `None` when a worry level overflows before them.
+    fn snapshot(
+        &self,
+        part: Part,
+        input: &[u8],
+        steps: usize,
+    ) -> Result<Option<String>, ParseError> {
+        let mut monkeys = parse(as_str(input)?)?;
+        let step = steps.min(round_count(part));
+        if monkeys.play(part, 0..step).is_none() {
+            return Ok(None);
+        }
+        let snapshot = Snapshot {
+            day: 11,
+            part,
+            step,
+            state: monkeys,
+        };
+        Ok(Some(snapshot.to_json()))
+    }
+
+    fn resume(&self, snapshot: &str) -> Result<Option<Answer>, String> {
+        let Snapshot {
+            part,
+            step,
+            state: mut monkeys,
+            ..
+        } = Snapshot::<Day11>::from_json(snapshot, 11)?;
+        monkeys.check()?;
+        let answer = match monkeys.play(part, step..round_count(part)) {
+            Some(()) => monkeys.end().into(),
+            None => Answer::Unsolved,
+        };
+        Ok(Some(answer))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Day11, ParseError> {
+    Day11::from(input)
+}
+
+/// Monkey business after 20 rounds, `None` when a worry level overflows.
+pub fn part1(monkeys: &Day11) -> Option<usize> {
+    let mut monkeys = monkeys.clone();
+    monkeys.play(Part::One, 0..round_count(Part::One))?;
+    Some(monkeys.end())
+}
+
+/// Monkey business after 10000 rounds without relief.
+pub fn part2(monkeys: &Day11) -> Option<usize> {
+    let mut monkeys = monkeys.clone();
+    monkeys.play(Part::Two, 0..round_count(Part::Two))?;
+    Some(monkeys.end())
+}
+
+/// Notes on `size` monkeys, 3 to 9 of them, each with a prime divisor of its
+/// own. The worry levels of part 1 stay in range.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    const PRIMES: [Item; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    let count = size.clamp(3, PRIMES.len());
+    loop {
+        let mut tests = PRIMES;
+        tests.shuffle(rng);
+        let square = rng.random_range(0..count);
+
+        let mut out = String::new();
+        for (m, test) in tests.iter().take(count).enumerate() {
+            let items: Vec<String> = (0..rng.random_range(1..=8))
+                .map(|_| rng.random_range(50..100).to_string())
+                .collect();
+            let operation = if m == square {
+                "* old".to_owned()
+            } else if rng.random_bool(0.5) {
+                format!("+ {}", rng.random_range(1..=8))
+            } else {
+                format!("* {}", rng.random_range(2..=19))
+            };
+            let mut others: Vec<usize> = (0..count).filter(|&o| o != m).collect();
+            others.shuffle(rng);
+
+            if m > 0 {
+                out.push('\n');
+            }
+            writeln!(out, "Monkey {m}:").unwrap();
+            writeln!(out, "  Starting items: {}", items.join(", ")).unwrap();
+            writeln!(out, "  Operation: new = old {operation}").unwrap();
+            writeln!(out, "  Test: divisible by {test}").unwrap();
+            writeln!(out, "    If true: throw to monkey {}", others[0]).unwrap();
+            writeln!(out, "    If false: throw to monkey {}", others[1]).unwrap();
+        }
+
+        if part1(&parse(&out).unwrap()).is_some() {
+            return out;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn score() {
+        // let score = part1(INPUT);
+
+        // println!("Score: {score}");
+
+        // assert_eq!(score, 1651);
+        let mut monkeys = Day11::from(INPUT).unwrap();
+        println!("{monkeys:#?}");
+
+        for _ in 0..20 {
+            monkeys.next().unwrap();
+        }
+
+        assert_eq!(monkeys.end(), 10605);
+    }
+
+    #[test]
+    fn example() {
+        let monkeys = parse(INPUT).unwrap();
+
+        assert_eq!(part1(&monkeys), Some(10605));
+        assert_eq!(part2(&monkeys), Some(2713310158));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = Item::MAX.to_string();
+        let monkeys = parse(&INPUT.replace("79, 98", &max)).unwrap();
+        assert_eq!(part1(&monkeys), None);
+        assert!(part2(&monkeys).is_some());
+        assert_eq!(monkeys.clone().rounds(Part::One).count(), 0);
+
+        let e = parse(&INPUT.replace("by 17", "by 10000000000000000")).unwrap_err();
+        assert_eq!(
+            (e.line, e.msg.as_str()),
+            (25, "divisors multiply past 64 bits")
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(&INPUT.replace("old + 6", "old - 6")).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (10, 26, "-"));
+        let e = parse(&INPUT.replace("monkey 3", "monkey 4")).unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (6, "no such monkey"));
+        let e = parse(&INPUT.replace("79, 60", "79, x")).unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (16, "x"));
+    }
+
+    //#[ignore = "reason"]
+    #[test]
+    fn score2() {
+        let mut monkeys = Day11::from(INPUT).unwrap();
+
+
+        let lcm = monkeys.0.iter().map(|n| n.test).product::<u64>();
+        println!("lcm {lcm}");
+    
+        for _n in 0..10000 {
+            //println!("round: {n}");
+            monkeys.next_part2(lcm);
+        }
+    
+        assert_eq!(monkeys.end(), 2713310158);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(11);
+        for size in [1, 4, 9, 9, 9] {
+            let monkeys = parse(&generate(&mut rng, size)).unwrap();
+            assert_eq!(monkeys.0.len(), size.max(3));
+            assert!(part1(&monkeys).unwrap() <= part2(&monkeys).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rounds() {
+        let mut monkeys = parse(INPUT).unwrap();
+        let rounds: Vec<_> = monkeys.rounds(Part::One).take(20).collect();
+        assert_eq!(rounds[0].items[0], [20, 23, 27, 26]);
+        assert!(rounds[0].items[2].is_empty());
+        assert_eq!(rounds[19].inspected, [101, 95, 7, 105]);
+
+        let mut monkeys = parse(INPUT).unwrap();
+        let round = monkeys.rounds(Part::Two).nth(999).unwrap();
+        assert_eq!(round.inspected, [5204, 4792, 199, 5192]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let json = Solver
+            .snapshot(Part::One, INPUT.as_bytes(), 1)
+            .unwrap()
+            .unwrap();
+        let after = Snapshot::<Day11>::from_json(&json, 11).unwrap();
+        assert_eq!(after.step, 1);
+        assert_eq!(after.state.0[0].items, [20, 23, 27, 26]);
+        assert_eq!(after.state.0[1].items, [2080, 25, 167, 207, 401, 1046]);
+        assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(10605))));
+
+        let json = Solver
+            .snapshot(Part::Two, INPUT.as_bytes(), 5000)
+            .unwrap()
+            .unwrap();
+        assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(2713310158))));
+
+        let json = json.replace("\"test\": 23", "\"test\": 0");
+        assert_eq!(
+            Solver.resume(&json),
+            Err("monkey 0 can't be played".to_owned())
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use aoc_common::{bfs, Answer, Frame, Grid, Idx, Loc, ParseError, Part, Paths, Solution};
+use rand::{Rng, RngCore};
+
+type IdxType = u16;
+
+type Dist = u16;
+
+/// Next value +1 higher, equal or lower.
+fn can_climb(from: u8, to: u8) -> bool {
+    to <= from + 1
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Nodes {
+    points: Grid<u8>,
+    start: Idx<IdxType>,
+    end: Idx<IdxType>,
+}
+
+impl Nodes {
+    fn from(input: &[u8]) -> Result<Self, ParseError> {
+        let mut points = Grid::parse(
+            input,
+            |p| matches!(p, b'a'..=b'z' | b'S' | b'E').then_some(p),
+            "expected a height",
+        )?;
+
+        if points.len() > usize::from(IdxType::MAX) {
+            return Err(ParseError::at(input, 0, "map is too large"));
+        }
+        let missing = |mark| ParseError::at(input, input.len(), format!("no `{mark}` on the map"));
+
+        let find = |points: &Grid<u8>, mark| (0..points.len()).find(|&i| points[i] == mark);
+
+        let idx = find(&points, b'S').ok_or_else(|| missing('S'))?;
+        points[idx] = b'a';
+
+        let end_idx = find(&points, b'E').ok_or_else(|| missing('E'))?;
+        points[end_idx] = b'z';
+
+        Ok(Self {
+            points,
+            start: Idx::from(idx),
+            end: Idx::from(end_idx),
+        })
+    }
+
+    fn get(&self, idx: Idx<IdxType>) -> u8 {
+        self.points[idx.as_usize()]
+    }
+
+    /// Neighbours that can be reached from `idx`.
+    fn get_neighbors_idx(&self, idx: Idx<IdxType>) -> impl Iterator<Item = Idx<IdxType>> + '_ {
+        let from = self.get(idx);
+
+        self.points
+            .neighbours4(self.points.loc(idx.as_usize()))
+            .map(|n| Idx::from(self.points.idx(n)))
+            .filter(move |&n| can_climb(from, self.get(n)))
+    }
+
+    fn distances(&self, start: Idx<IdxType>) -> Paths<Idx<IdxType>, usize> {
+        bfs(
+            start,
+            |&idx| self.get_neighbors_idx(idx),
+            |&idx| idx == self.end,
+        )
+    }
+
+    /// Neighbours from which `idx` can be reached.
+    fn get_climbers_idx(&self, idx: Idx<IdxType>) -> impl Iterator<Item = Idx<IdxType>> + '_ {
+        let to = self.get(idx);
+
+        self.points
+            .neighbours4(self.points.loc(idx.as_usize()))
+            .map(|n| Idx::from(self.points.idx(n)))
+            .filter(move |&n| can_climb(self.get(n), to))
+    }
+
+    /// Part 2, back from the end down to the closest of all the lowest points.
+    fn descent(&self) -> Paths<Idx<IdxType>, usize> {
+        bfs(
+            self.end,
+            |&idx| self.get_climbers_idx(idx),
+            |&idx| self.get(idx) == b'a',
+        )
+    }
+
+    /// The height map with `path` in blue.
+    fn frame(&self, path: &[Idx<IdxType>]) -> Frame {
+        let mut frame = Frame::new("");
+        for idx in 0..self.points.len() {
+            let loc = self.points.loc(idx);
+            let ch = match Idx::from(idx) {
+                i if i == self.end => 'E',
+                i if i == self.start => 'S',
+                _ => char::from(self.points[idx]),
+            };
+            let color = if path.contains(&Idx::from(idx)) {
+                21
+            } else {
+                40
+            };
+            frame.paint(loc.x, loc.y, ch, color);
+        }
+        frame
+    }
+}
+
+/// Height map with start and end.
+pub fn parse(input: &[u8]) -> Result<Nodes, ParseError> {
+    Nodes::from(input)
+}
+
+/// `None` when `E` cannot be reached.
+pub fn part1(nodes: &Nodes) -> Option<Dist> {
+    nodes
+        .distances(nodes.start)
+        .goal_distance()
+        .map(|d| Dist::try_from(d).unwrap())
+}
+
+/// `None` when `E` cannot be reached from any `a`.
+pub fn part2(nodes: &Nodes) -> Option<Dist> {
+    nodes
+        .descent()
+        .goal_distance()
+        .map(|d| Dist::try_from(d).unwrap())
+}
+
+/// Height map of `size` rows, and wide enough to climb from `a` to `z` in
+/// single steps. A path from `S` in the top left to `E` in the bottom right
+/// climbs all the way, the rest is lower.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let height = size.clamp(5, 100);
+    let width = 2 * height + 25;
+    let steps = width + height - 2;
+    // Fits, `steps` is at least 25.
+    let level = |x: usize, y: usize| b'a' + ((x + y) * 25 / steps) as u8;
+
+    let mut map = Grid::new(width, height, b'a');
+    for idx in 0..map.len() {
+        let loc = map.loc(idx);
+        map[idx] = rng.random_range(b'a'..=level(loc.x, loc.y));
+    }
+    let (mut x, mut y) = (0, 0);
+    while (x, y) != (width - 1, height - 1) {
+        if y == height - 1 || (x < width - 1 && rng.random_bool(0.5)) {
+            x += 1;
+        } else {
+            y += 1;
+        }
+        map[Loc::new(x, y)] = level(x, y);
+    }
+    map[0] = b'S';
+    map[Loc::new(width - 1, height - 1)] = b'E';
+
+    let mut out = String::with_capacity((width + 1) * height);
+    for row in map.rows() {
+        out.push_str(core::str::from_utf8(row).unwrap());
+        out.push('\n');
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn animate(
+        &self,
+        part: Part,
+        input: &[u8],
+        draw: &mut dyn FnMut(&Frame),
+    ) -> Result<Answer, ParseError> {
+        let nodes = parse(input)?;
+        let path = match part {
+            Part::One => nodes.distances(nodes.start).path(nodes.end),
+            Part::Two => {
+                let paths = nodes.descent();
+                paths.goal().and_then(|a| paths.path(a)).map(|mut path| {
+                    path.reverse();
+                    path
+                })
+            }
+        };
+        let Some(path) = path else {
+            return Ok(Answer::Unsolved);
+        };
+        for step in 1..=path.len() {
+            draw(
+                nodes
+                    .frame(&path[..step])
+                    .caption(format!("step {}", step - 1)),
+            );
+        }
+        Ok(Dist::try_from(path.len() - 1).unwrap().into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2, Idx, Nodes, Solver};
+    use aoc_common::{Answer, Part, Solution};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    //   01234567
+    // 0 Sabqponm
+    // 1 abcryxxl
+    // 2 accszExk
+    // 3 acctuvwj
+    // 4 abdefghi
+
+    #[test]
+    fn test_distance() {
+        let nodes = Nodes::from(INPUT).unwrap();
+        assert_eq!((nodes.points.width(), nodes.points.height()), (8, 5));
+
+        let paths = nodes.distances(nodes.start);
+        let end = paths.goal().unwrap();
+
+        assert_eq!(end, Idx::from(21));
+
+        let path = paths.path(end).unwrap();
+        assert_eq!(path.first(), Some(&Idx(0)));
+
+        println!("{}", nodes.frame(&path));
+
+        assert_eq!(paths.goal_distance(), Some(31));
+        assert_eq!(path.len(), 32);
+    }
+
+    #[test]
+    fn test_path() {
+        let nodes = Nodes::from(INPUT).unwrap();
+        assert_eq!((nodes.points.width(), nodes.points.height()), (8, 5));
+
+        let path = nodes.distances(nodes.start).path(nodes.end).unwrap();
+
+        for step in path.windows(2) {
+            assert!(nodes.get_neighbors_idx(step[0]).any(|n| n == step[1]));
+        }
+        assert_eq!(path.last(), Some(&nodes.end));
+    }
+
+    #[test]
+    fn test_example() {
+        let nodes = parse(INPUT).unwrap();
+
+        assert_eq!(part1(&nodes), Some(31));
+        assert_eq!(part2(&nodes), Some(29));
+
+        let walled = parse(b"SzE\n").unwrap();
+        assert_eq!((part1(&walled), part2(&walled)), (None, None));
+        assert_eq!(Solver.part1(b"SzE\n"), Ok(Answer::Unsolved));
+        assert_eq!(
+            Solver.animate(Part::Two, b"SzE\n", &mut |_| ()),
+            Ok(Answer::Unsolved)
+        );
+
+        let e = parse(b"Sab\nab\nbcE").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (2, "rows differ in length"));
+        let e = parse(b"Sab\nabc").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, ""));
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(12);
+        for size in [1, 10, 20, 41] {
+            let nodes = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            let shortest = usize::from(part1(&nodes).unwrap());
+            assert!(shortest >= nodes.points.width() + nodes.points.height() - 2);
+
+            // Generated maps have `a` all over, not just on the left edge.
+            let from_every_a = (0..nodes.points.len())
+                .filter(|&i| nodes.points[i] == b'a')
+                .filter_map(|i| nodes.distances(Idx::from(i)).goal_distance())
+                .min()
+                .map(|d| u16::try_from(d).unwrap());
+            assert_eq!(part2(&nodes), from_every_a);
+            assert!(part2(&nodes) <= part1(&nodes));
+        }
+    }
+}
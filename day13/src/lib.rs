@@ -0,0 +1,296 @@
+use aoc_common::{as_str, str_lines, Answer, ParseError, Solution};
+use nom::{
+    self,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::{all_consuming, cut},
+    multi::separated_list0,
+    sequence::delimited,
+    Finish, IResult, Parser,
+};
+use rand::{Rng, RngCore};
+use std::fmt::{self, Write};
+
+type Item = u8;
+
+#[derive(Debug, Eq)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Number(Item),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::List(l0), Self::List(r0)) => l0 == r0,
+            (Self::Number(l0), Self::Number(r0)) => l0 == r0,
+            (Self::Number(n1), Self::List(l2)) => &vec![Packet::Number(*n1)] == l2,
+            (Self::List(l1), Self::Number(n2)) => l1 == &vec![Packet::Number(*n2)],
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Packet::Number(n1), Packet::Number(n2)) => n1.cmp(n2),
+            (Packet::Number(n1), Packet::List(l2)) => vec![Packet::Number(*n1)].cmp(l2),
+            (Packet::List(l1), Packet::Number(n2)) => l1.cmp(&vec![Packet::Number(*n2)]),
+            (Packet::List(l1), Packet::List(l2)) => l1.cmp(l2),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::List(list) => {
+                write!(f, "[")?;
+                for (i, p) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{p}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Pair {
+    l: Packet,
+    r: Packet,
+}
+
+fn packet(input: &str) -> IResult<&str, Packet> {
+    alt((
+        delimited(
+            tag("["),
+            cut(separated_list0(tag(","), packet)),
+            cut(tag("]")),
+        )
+        .map(Packet::List),
+        complete::u8.map(Packet::Number),
+    ))(input)
+}
+
+/// Packet pairs, separated by a blank line.
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let err = |at: &str, msg| ParseError::at_slice(input.as_bytes(), at.as_bytes(), msg);
+    let packet_line = |line| {
+        all_consuming(packet)(line)
+            .finish()
+            .map(|(_, p)| p)
+            .map_err(|e| err(e.input, "expected a packet"))
+    };
+
+    let mut list = Vec::new();
+    let mut lines = str_lines(input);
+    while let Some(l) = lines.next() {
+        let r = lines
+            .next()
+            .ok_or_else(|| err(&l[l.len()..], "expected a second packet"))?;
+        list.push(Pair {
+            l: packet_line(l)?,
+            r: packet_line(r)?,
+        });
+
+        match lines.next() {
+            None | Some("") => (),
+            Some(extra) => return Err(err(extra, "expected a blank line")),
+        }
+    }
+    Ok(list)
+}
+
+pub fn part1(list: &[Pair]) -> usize {
+    list.iter()
+        .zip(1..)
+        .filter_map(|(Pair { l, r }, i)| match l.cmp(r) {
+            std::cmp::Ordering::Less => Some(i),
+            // Equal packets are not in the right order.
+            std::cmp::Ordering::Equal | std::cmp::Ordering::Greater => None,
+        })
+        .sum()
+}
+
+/// Decoder key, the divider packets are added here.
+pub fn part2(list: &[Pair]) -> usize {
+    let dividers = [
+        Packet::List(vec![Packet::List(vec![Packet::Number(2)])]),
+        Packet::List(vec![Packet::List(vec![Packet::Number(6)])]),
+    ];
+
+    let mut nl: Vec<&Packet> = list
+        .iter()
+        .flat_map(|Pair { l, r }| [l, r])
+        .chain(&dividers)
+        .collect();
+    nl.sort();
+
+    nl.iter()
+        .zip(1..)
+        //.inspect(|p| println!("{p:?}"))
+        .filter_map(|(p, i)| {
+            if **p == Packet::List(vec![Packet::List(vec![Packet::Number(2)])])
+                || **p == Packet::List(vec![Packet::List(vec![Packet::Number(6)])])
+            {
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .product()
+}
+
+fn random_packet(rng: &mut dyn RngCore, depth: u8) -> Packet {
+    if depth > 0 && rng.random_bool(0.7) {
+        let len = rng.random_range(0..=5);
+        Packet::List((0..len).map(|_| random_packet(rng, depth - 1)).collect())
+    } else {
+        Packet::Number(rng.random_range(0..=10))
+    }
+}
+
+/// `size` pairs of random packets, none of them written like a divider packet.
+/// A pair can still compare equal, and so can a packet and a divider: `[2]`
+/// and `[[2]]` do.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let dividers = [
+        Packet::List(vec![Packet::List(vec![Packet::Number(2)])]),
+        Packet::List(vec![Packet::List(vec![Packet::Number(6)])]),
+    ];
+    let mut packet = || loop {
+        let p = Packet::List(
+            (0..rng.random_range(0..=5))
+                .map(|_| random_packet(rng, 3))
+                .collect(),
+        );
+        if !dividers.contains(&p) {
+            break p;
+        }
+    };
+
+    let mut out = String::new();
+    for i in 0..size.max(1) {
+        let (l, r) = (packet(), packet());
+        if i > 0 {
+            out.push('\n');
+        }
+        writeln!(out, "{l}\n{r}").unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{packet, parse, part1, part2, Item, Packet};
+    use proptest::prelude::*;
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn example_part1() {
+        let list = parse(INPUT).unwrap();
+        assert_eq!(part1(&list), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        let list = parse(INPUT).unwrap();
+
+        assert_eq!(part2(&list), 140);
+    }
+
+    #[test]
+    fn test_equal_pair() {
+        let list = parse("[1]\n[1]\n\n[1]\n[2]\n").unwrap();
+        assert_eq!(part1(&list), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("[1]\n[2]\n\n[1,x]\n[3]").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 3, ",x]"));
+        let e = parse("[1]\n[2]\n\n[1]").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 4, ""));
+        let e = parse("[1]\n[2]\n[3]").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (3, "expected a blank line"));
+
+        assert_eq!(parse("[1]\r\n[2]\r\n\r\n[3]\r\n[4]\r\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(13);
+        for size in [1, 150] {
+            let input = super::generate(&mut rng, size);
+            let pairs = parse(&input).unwrap();
+            assert_eq!(pairs.len(), size);
+            assert_eq!(pairs[0].l.to_string(), input.lines().next().unwrap());
+            assert!(part1(&pairs) <= size * (size + 1) / 2);
+            assert!(part2(&pairs) >= 2);
+        }
+    }
+
+    fn any_packet() -> impl Strategy<Value = Packet> {
+        any::<Item>()
+            .prop_map(Packet::Number)
+            .prop_recursive(4, 32, 5, |inner| {
+                prop::collection::vec(inner, 0..5).prop_map(Packet::List)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_packet_round_trip(p in any_packet()) {
+            let text = p.to_string();
+            prop_assert_eq!(packet(&text).unwrap(), ("", p));
+        }
+
+        #[test]
+        fn prop_packet_order(a in any_packet(), b in any_packet(), c in any_packet()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cmp(&b) == std::cmp::Ordering::Equal);
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+    }
+}
@@ -0,0 +1,608 @@
+use aoc_common::{
+    lines, text_grid, try_slice_to_number, Answer, Frame, Grid, Image, Loc, Palette, ParseError,
+    Part, Snapshot, Solution,
+};
+use log::debug;
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+#[allow(dead_code)]
+mod pos {
+    pub const EMPTY: u8 = b'.';
+    pub const WALL: u8 = b'#';
+    pub const SAND: u8 = b'O';
+    pub const START: u8 = b'+';
+}
+
+const FIELD_X: usize = 500 * 2;
+const FIELD_Y: usize = 168;
+#[derive(Serialize, Deserialize)]
+struct Sim {
+    #[serde(with = "text_grid")]
+    buf: Grid<u8>,
+    tail: Vec<Loc<usize>>,
+    /// Rock and sand in the order they were placed, for [`Sim::frame`].
+    drawn: Vec<Loc<usize>>,
+}
+
+impl Sim {
+    fn new() -> Self {
+        let mut ret = Self {
+            buf: Grid::new(FIELD_X, FIELD_Y, pos::EMPTY),
+            tail: Vec::with_capacity(1024),
+            drawn: Vec::with_capacity(1024),
+        };
+
+        ret.tail.push(Vector(500, 0).loc());
+        ret
+    }
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new("");
+        frame.set(500, 0, '+');
+        for &loc in &self.drawn {
+            let Loc { x, y } = loc;
+            match self.buf[loc] {
+                pos::SAND => frame.paint(x, y, 'o', 11).focus(x, y),
+                c => frame.set(x, y, char::from(c)),
+            };
+        }
+        frame
+    }
+
+    /// The rock and sand, cut to the width of the pile but with the floor.
+    fn image(&self) -> Image {
+        let palette = Palette::new([20, 20, 40])
+            .with(pos::WALL, [120, 120, 120])
+            .with(pos::SAND, [230, 190, 80]);
+        let floor = |y: usize| self.buf.row(y).iter().all(|&c| c == pos::WALL);
+
+        let (mut min_x, mut max_x, mut max_y) = (FIELD_X, 0, 0);
+        for &Loc { x, y } in &self.drawn {
+            max_y = max_y.max(y);
+            if !floor(y) {
+                min_x = min_x.min(x);
+                max_x = max_x.max(x);
+            }
+        }
+        min_x = min_x.min(max_x).saturating_sub(1);
+
+        Image::from_grid(&self.buf, |c| palette.color(c))
+            .cropped(Loc::new(min_x, 0), Loc::new(max_x + 2 - min_x, max_y + 1))
+    }
+
+    // 498,4 -> 498,6 -> 496,6
+    fn draw_vectors(&mut self, vecs: &[Vector]) {
+        let toward = |from: usize, to: usize| match from.cmp(&to) {
+            std::cmp::Ordering::Less => from + 1,
+            std::cmp::Ordering::Equal => from,
+            std::cmp::Ordering::Greater => from - 1,
+        };
+
+        let mut vecs = vecs.iter().map(Vector::loc);
+        let mut wall = vecs.next().unwrap();
+        self.buf[wall] = pos::WALL;
+        self.drawn.push(wall);
+
+        for p_next in vecs {
+            while wall != p_next {
+                wall = Loc::new(toward(wall.x, p_next.x), toward(wall.y, p_next.y));
+                self.buf[wall] = pos::WALL;
+                self.drawn.push(wall);
+            }
+        }
+    }
+
+    fn sim(&mut self) -> bool {
+        let mut loc = loop {
+            let Some(loc) = self.tail.pop() else {
+                debug!("Missing");
+                return false;
+            };
+            let start = self.buf[loc];
+            if start != pos::EMPTY {
+                continue;
+            };
+
+            break loc;
+        };
+
+        loop {
+            // last empty
+            self.tail.push(loc);
+
+            // Down, down left, down right.
+            let mut next = None;
+            for dx in [0, -1, 1] {
+                let below = loc.x.checked_add_signed(dx).map(|x| Loc::new(x, loc.y + 1));
+                match below.and_then(|b| Some((b, *self.buf.get(b)?))) {
+                    None => {
+                        debug!("outside buff x:{} y:{}", loc.x, loc.y);
+                        return false;
+                    }
+                    Some((b, pos::EMPTY)) => {
+                        next = Some(b);
+                        break;
+                    }
+                    Some(_) => (),
+                }
+            }
+            let Some(below) = next else {
+                break;
+            };
+            loc = below;
+        }
+        let last_valid = self.tail.pop().unwrap();
+        self.buf[last_valid] = pos::SAND;
+        self.drawn.push(last_valid);
+
+        true
+    }
+}
+
+/// Sand part way through pouring, with the floor still to come for part 2.
+/// One item per grain that comes to rest, see [`grains`].
+#[derive(Serialize, Deserialize)]
+pub struct Pour {
+    sim: Sim,
+    /// Grains at rest.
+    grains: u32,
+    /// Where the floor goes once sand falls off the board.
+    floor: Option<u16>,
+}
+
+impl Pour {
+    fn new(paths: &[Vec<Vector>], floor: bool) -> Self {
+        let (sim, max) = setup(paths);
+        Self {
+            sim,
+            grains: 0,
+            floor: floor.then_some(max + 2),
+        }
+    }
+
+    /// Drops sand until it falls off the board or the source is blocked, or
+    /// until there are `limit` grains at rest. `show` sees the board after
+    /// every grain.
+    fn run(&mut self, limit: Option<u32>, show: &mut dyn FnMut(&Sim)) {
+        while limit != Some(self.grains) && self.next().is_some() {
+            show(&self.sim);
+        }
+    }
+
+    /// A hand-edited snapshot could index outside the board.
+    fn check(&self) -> Result<(), String> {
+        if (self.sim.buf.width(), self.sim.buf.height()) != (FIELD_X, FIELD_Y) {
+            return Err(format!("expected a {FIELD_X}x{FIELD_Y} board"));
+        }
+        let mut locs = self.sim.tail.iter().chain(&self.sim.drawn);
+        if locs.any(|&loc| !self.sim.buf.contains(loc)) {
+            return Err("location outside the board".to_owned());
+        }
+        if self.floor.is_some_and(|y| usize::from(y) >= FIELD_Y) {
+            return Err("floor below the board".to_owned());
+        }
+        Ok(())
+    }
+}
+
+/// A grain of sand that came to rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grain {
+    pub x: usize,
+    pub y: usize,
+    /// Grains at rest, this one included.
+    pub grains: u32,
+}
+
+impl Iterator for Pour {
+    type Item = Grain;
+
+    fn next(&mut self) -> Option<Grain> {
+        loop {
+            if self.sim.sim() {
+                self.grains += 1;
+                let Loc { x, y } = *self.sim.drawn.last().unwrap();
+                return Some(Grain {
+                    x,
+                    y,
+                    grains: self.grains,
+                });
+            }
+            let y = self.floor.take()?;
+            self.sim.draw_vectors(&[Vector(0, y), Vector(999, y)]);
+            self.sim.tail.clear();
+            self.sim.tail.push(Vector(500, 0).loc());
+        }
+    }
+}
+
+/// The sand of `part` poured over the rock `paths`, grain by grain.
+pub fn grains(paths: &[Vec<Vector>], part: Part) -> Pour {
+    Pour::new(paths, part == Part::Two)
+}
+
+/// Rock paths, one per line.
+pub fn parse(input: &[u8]) -> Result<Vec<Vec<Vector>>, ParseError> {
+    lines(input).map(|line| Vector::from(input, line)).collect()
+}
+
+pub fn part1(paths: &[Vec<Vector>]) -> u32 {
+    pour(paths, false, &mut |_| ()).0
+}
+
+pub fn part2(paths: &[Vec<Vector>]) -> u32 {
+    pour(paths, true, &mut |_| ()).0
+}
+
+/// Grains at rest, with a `floor` two below the lowest rock for part 2. `show`
+/// sees the board after every grain.
+fn pour(paths: &[Vec<Vector>], floor: bool, show: &mut dyn FnMut(&Sim)) -> (u32, Sim) {
+    let mut pour = Pour::new(paths, floor);
+    pour.run(None, show);
+    (pour.grains, pour.sim)
+}
+
+/// Board with all rock paths drawn and the lowest rock y.
+fn setup(paths: &[Vec<Vector>]) -> (Sim, u16) {
+    let mut sim = Sim::new();
+    let mut max: u16 = 0;
+
+    for vecs in paths {
+        let max_y = vecs.iter().map(|v| v.1).max().unwrap();
+        max = max.max(max_y);
+        sim.draw_vectors(vecs);
+    }
+
+    (sim, max)
+}
+
+/// `size` rock paths below and around the sand source, spread out so that
+/// sand still falls off the bottom in part 1.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    // Fits, at most 160.
+    let spread = (20 + 2 * size).min(160) as u16;
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let mut x = rng.random_range(500 - spread..=500 + spread);
+        let mut y: u16 = rng.random_range(10..=160);
+        let mut path = format!("{x},{y}");
+        let mut horizontal = rng.random_bool(0.5);
+        for _ in 0..rng.random_range(1..=5) {
+            let step = rng.random_range(1..=8);
+            if horizontal {
+                x = if rng.random_bool(0.5) {
+                    x + step
+                } else {
+                    x - step
+                };
+            } else {
+                y = if y + step > 160 || rng.random_bool(0.5) && y - step >= 10 {
+                    y - step
+                } else {
+                    y + step
+                };
+            }
+            write!(path, " -> {x},{y}").unwrap();
+            horizontal = !horizontal;
+        }
+        writeln!(out, "{path}").unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn animate(
+        &self,
+        part: Part,
+        input: &[u8],
+        draw: &mut dyn FnMut(&Frame),
+    ) -> Result<Answer, ParseError> {
+        let mut grains = 0;
+        let (cnt, _) = pour(&parse(input)?, part == Part::Two, &mut |sim| {
+            grains += 1;
+            draw(sim.frame().caption(format!("grain {grains}")));
+        });
+        Ok(cnt.into())
+    }
+
+    fn image(&self, input: &[u8]) -> Result<Option<Image>, ParseError> {
+        let (_, sim) = pour(&parse(input)?, true, &mut |_| ());
+        Ok(Some(sim.image()))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    /// Steps are grains at rest.
+    fn snapshot(
+        &self,
+        part: Part,
+        input: &[u8],
+        steps: usize,
+    ) -> Result<Option<String>, ParseError> {
+        let mut pour = grains(&parse(input)?, part);
+        pour.run(Some(steps.try_into().unwrap_or(u32::MAX)), &mut |_| ());
+        let snapshot = Snapshot {
+            day: 14,
+            part,
+            step: pour.grains as usize,
+            state: pour,
+        };
+        Ok(Some(snapshot.to_json()))
+    }
+
+    fn resume(&self, snapshot: &str) -> Result<Option<Answer>, String> {
+        let mut pour = Snapshot::<Pour>::from_json(snapshot, 14)?.state;
+        pour.check()?;
+        pour.run(None, &mut |_| ());
+        Ok(Some(pour.grains.into()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Vector(u16, u16);
+
+impl Vector {
+    /// `x,y -> x,y -> ...`, `line` is part of `input`.
+    fn from(input: &[u8], line: &[u8]) -> Result<Vec<Self>, ParseError> {
+        let mut points = Vec::<Self>::with_capacity(10);
+
+        let mut arrow = false;
+        for p in line.split(|v| *v == b' ') {
+            // Points and arrows take turns.
+            if arrow {
+                if p != b"->" {
+                    return Err(ParseError::at_slice(input, p, "expected `->`"));
+                }
+                arrow = false;
+                continue;
+            }
+            arrow = true;
+
+            let (x, y) = match p.iter().position(|v| *v == b',') {
+                Some(c) => (&p[..c], &p[c + 1..]),
+                None => return Err(ParseError::at_slice(input, p, "expected `x,y`")),
+            };
+            let number = |v: &[u8], max: usize| {
+                try_slice_to_number::<u16>(v)
+                    .filter(|&n| usize::from(n) < max)
+                    .ok_or_else(|| {
+                        ParseError::at_slice(input, v, format!("expected a number below {max}"))
+                    })
+            };
+            let point = Self(number(x, FIELD_X)?, number(y, FIELD_Y - 2)?);
+
+            if let Some(prev) = points.last() {
+                if prev.0 != point.0 && prev.1 != point.1 {
+                    return Err(ParseError::at_slice(input, p, "path is not straight"));
+                }
+            }
+            points.push(point);
+        }
+        if !arrow {
+            return Err(ParseError::at_slice(
+                input,
+                &line[line.len()..],
+                "expected `x,y`",
+            ));
+        }
+        Ok(points)
+    }
+
+    fn loc(&self) -> Loc<usize> {
+        Loc::new(usize::from(self.0), usize::from(self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{grains, lines, parse, part1, part2, Grain, Loc, Pour, Sim, Solver, Vector};
+    use aoc_common::{Answer, Part, Snapshot, Solution};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_points() {
+        let mut points = lines(INPUT);
+
+        let pi = points.next().unwrap();
+        let vecs = Vector::from(INPUT, pi).unwrap();
+        assert_eq!(vecs, vec![Vector(498, 4), Vector(498, 6), Vector(496, 6)]);
+
+        let pi = points.next().unwrap();
+        let vecs = Vector::from(INPUT, pi).unwrap();
+        assert_eq!(
+            vecs,
+            vec![
+                Vector(503, 4),
+                Vector(502, 4),
+                Vector(502, 9),
+                Vector(494, 9)
+            ]
+        );
+
+        assert_eq!(points.next(), None);
+
+        let e = parse(b"498,4 -> 498,6\n503,4 -> 502,x").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 14, "x"));
+        let e = parse(b"498,4 -> 497,6").unwrap_err();
+        assert_eq!((e.column, e.msg.as_str()), (10, "path is not straight"));
+        let e = parse(b"498,4 -> 498,400").unwrap_err();
+        assert_eq!(e.text, "400");
+        let e = parse(b"->\n").unwrap_err();
+        assert_eq!((e.column, e.msg.as_str()), (1, "expected `x,y`"));
+        let e = parse(b"498,4 ->").unwrap_err();
+        assert_eq!((e.column, e.msg.as_str()), (9, "expected `x,y`"));
+        let e = parse(b"498,4 498,6").unwrap_err();
+        assert_eq!((e.column, e.msg.as_str()), (7, "expected `->`"));
+    }
+
+    #[test]
+    fn test_draw_vectors() {
+        let mut sim = Sim::new();
+        sim.draw_vectors(&[Vector(498, 4), Vector(498, 6), Vector(496, 6)]);
+        assert_eq!(
+            sim.drawn,
+            [
+                Loc::new(498, 4),
+                Loc::new(498, 5),
+                Loc::new(498, 6),
+                Loc::new(497, 6),
+                Loc::new(496, 6)
+            ]
+        );
+        assert_eq!(sim.buf[Loc::new(497, 6)], b'#');
+        assert_eq!(Vector(500, 1).loc(), Loc::new(500, 1));
+    }
+
+    #[test]
+    fn test_draw_example() {
+        let points = lines(INPUT);
+
+        let mut sim = Sim::new();
+
+        for pi in points {
+            let vecs = Vector::from(INPUT, pi).unwrap();
+            sim.draw_vectors(&vecs);
+        }
+
+        println!("{}", sim.frame());
+
+        for c in 0..26 {
+            if !sim.sim() {
+                assert_eq!(c, 24);
+                break;
+            }
+        }
+
+        println!("{}", sim.frame());
+
+        // assert!(false);
+    }
+
+    #[test]
+    fn test_draw_example_part2() {
+        let points = lines(INPUT);
+
+        let mut sim = Sim::new();
+
+        let mut max: u16 = 0;
+
+        for pi in points {
+            let vecs = Vector::from(INPUT, pi).unwrap();
+            let max_y = vecs.iter().map(|v| v.1).max().unwrap();
+            max = max.max(max_y);
+            sim.draw_vectors(&vecs);
+        }
+
+        assert_eq!(max, 9);
+
+        max += 2;
+
+        let vecs = vec![Vector(0, max), Vector(999, max)];
+        sim.draw_vectors(&vecs);
+
+        println!("{}", sim.frame());
+
+        for c in 0..100 {
+            if !sim.sim() {
+                assert_eq!(c, 93);
+                break;
+            }
+        }
+
+        println!("{}", sim.frame());
+
+        let image = sim.image();
+        assert_eq!((image.width(), image.height()), (23, 12));
+        assert_eq!(image.get(11, 0), Some([230, 190, 80]));
+        assert_eq!(image.get(0, 11), Some([120, 120, 120]));
+
+        // assert!(false);
+    }
+
+    #[test]
+    fn test_example() {
+        let paths = parse(INPUT).unwrap();
+
+        assert_eq!(part1(&paths), 24);
+        assert_eq!(part2(&paths), 93);
+    }
+
+    #[test]
+    fn test_grains() {
+        let paths = parse(INPUT).unwrap();
+        let first = Grain {
+            x: 500,
+            y: 8,
+            grains: 1,
+        };
+        assert_eq!(grains(&paths, Part::One).next(), Some(first));
+        assert_eq!(grains(&paths, Part::One).count(), 24);
+
+        let last = grains(&paths, Part::Two).last().unwrap();
+        assert_eq!((last.x, last.y, last.grains), (500, 0, 93));
+        let on_floor = grains(&paths, Part::Two).filter(|g| g.y == 10).count();
+        assert!(on_floor > 0);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let json = Solver.snapshot(Part::One, INPUT, 10).unwrap().unwrap();
+        let after = Snapshot::<Pour>::from_json(&json, 14).unwrap();
+        assert_eq!(after.step, 10);
+        let sand = after
+            .state
+            .sim
+            .buf
+            .rows()
+            .flatten()
+            .filter(|&&c| c == b'O')
+            .count();
+        assert_eq!(sand, 10);
+        assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(24))));
+
+        for steps in [0, 10, 50] {
+            let json = Solver.snapshot(Part::Two, INPUT, steps).unwrap().unwrap();
+            assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(93))));
+        }
+
+        let json = json.replacen("\"tail\": [", "\"tail\": [{\"x\": 5000, \"y\": 0},", 1);
+        let e = Solver.resume(&json).unwrap_err();
+        assert_eq!(e, "location outside the board");
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(14);
+        for size in [1, 10, 100] {
+            let paths = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(paths.len(), size);
+            assert!(part1(&paths) < part2(&paths));
+        }
+    }
+}
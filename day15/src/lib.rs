@@ -0,0 +1,481 @@
+#![deny(clippy::pedantic)]
+
+use aoc_common::{lines, Answer, Image, ParseError, Solution};
+use core::{fmt, str::from_utf8};
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+pub type Ipos = i32;
+
+type Upos = u32;
+
+/// Row that part 1 scans in the puzzle input.
+pub const Y_ROW: Ipos = 2_000_000;
+
+/// Size of the part 2 search area in the puzzle input.
+pub const N_MAX: Ipos = 4_000_000;
+
+/// Largest side of the coverage picture.
+const COVERAGE_SIZE: usize = 800;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Point(Ipos, Ipos);
+
+impl Point {
+    // part = "x=123, y=123", a slice of `input`
+
+    fn parse(input: &[u8], part: &[u8]) -> Result<Self, ParseError> {
+        let syntax = || ParseError::at_slice(input, part, "expected `x=<n>, y=<n>`");
+        let number = |v: &[u8]| {
+            from_utf8(v)
+                .ok()
+                .and_then(|s| s.parse::<Ipos>().ok())
+                .ok_or_else(|| ParseError::at_slice(input, v, "expected a number"))
+        };
+
+        let comma = part.iter().position(|v| *v == b',').ok_or_else(syntax)?;
+        let x = part[..comma].strip_prefix(b"x=").ok_or_else(syntax)?;
+        let y = part[comma + 1..].strip_prefix(b" y=").ok_or_else(syntax)?;
+
+        Ok(Self(number(x)?, number(y)?))
+    }
+
+    /// `None` when it does not fit in [`Upos`].
+    fn distance(&self, beacon: &Point) -> Option<Upos> {
+        self.0
+            .abs_diff(beacon.0)
+            .checked_add(self.1.abs_diff(beacon.1))
+    }
+
+    fn in_range(&self, dis: Upos, y: Ipos) -> Option<Upos> {
+        let y_dis = self.1.abs_diff(y);
+
+        if y_dis > dis {
+            None
+        } else {
+            Some(dis - y_dis)
+        }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x={}, y={}", self.0, self.1)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Info {
+    sensor: Point,
+    beacon: Point,
+    distance: Upos,
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sensor at {}: closest beacon is at {}",
+            self.sensor, self.beacon
+        )
+    }
+}
+
+/// Sensors with their closest beacon.
+///
+/// # Errors
+///
+/// On a malformed line.
+pub fn parse(input: &[u8]) -> Result<Vec<Info>, ParseError> {
+    const SENSOR: &[u8] = b"Sensor at ";
+    const BEACON: &[u8] = b": closest beacon is at ";
+
+    let mut ret = Vec::with_capacity(20);
+
+    for line in lines(input) {
+        // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        let syntax = || {
+            ParseError::at_slice(
+                input,
+                line,
+                "expected `Sensor at ..: closest beacon is at ..`",
+            )
+        };
+        let fields = line.strip_prefix(SENSOR).ok_or_else(syntax)?;
+        let split = fields
+            .windows(BEACON.len())
+            .position(|w| w == BEACON)
+            .ok_or_else(syntax)?;
+
+        let sensor = Point::parse(input, &fields[..split])?;
+        let beacon = Point::parse(input, &fields[split + BEACON.len()..])?;
+        let distance = sensor
+            .distance(&beacon)
+            .ok_or_else(|| ParseError::at_slice(input, line, "beacon too far from the sensor"))?;
+
+        ret.push(Info {
+            sensor,
+            beacon,
+            distance,
+        });
+    }
+
+    Ok(ret)
+}
+
+/// Positions on row `y_row` where no beacon can be.
+///
+/// # Panics
+///
+/// When a sensor range does not fit in [`Ipos`].
+#[must_use]
+pub fn part1(data: &[Info], y_row: Ipos) -> usize {
+    let mut beacon_not_at = Vec::<Ipos>::with_capacity(1000);
+
+    for point in data {
+        let sensor = &point.sensor;
+
+        let dis = point.distance;
+
+        if let Some(r) = sensor.in_range(dis, y_row) {
+            let r = Ipos::try_from(r).unwrap();
+
+            for i in sensor.0 - r..sensor.0 + r {
+                beacon_not_at.push(i);
+            }
+        }
+
+        if point.beacon.1 == y_row {
+            beacon_not_at.push(point.beacon.0);
+        }
+
+        beacon_not_at.sort_unstable();
+
+        beacon_not_at.dedup();
+    }
+
+    beacon_not_at.len()
+}
+
+/// Tuning frequency of the only spot within `0..=n_max` not covered by a sensor.
+///
+/// # Panics
+///
+/// When a sensor range does not fit in [`Ipos`].
+#[must_use]
+pub fn part2(data: &[Info], n_max: Ipos) -> Option<u64> {
+    'lus: for y in 0..n_max {
+        let mut x: i32 = -1;
+        'x: loop {
+            x += 1;
+            if x > n_max {
+                continue 'lus;
+            }
+
+            for point in data {
+                let sensor = &point.sensor;
+                let dis = point.distance;
+
+                if let Some(r) = sensor.in_range(dis, y) {
+                    let xs = sensor.0;
+                    // Not in range
+                    if x.abs_diff(xs) > r {
+                        // println!("ND: x{x} y{y} n{n}");
+                        continue;
+                    }
+
+                    x = x.max(xs - 1 + Ipos::try_from(r).unwrap());
+                    // println!("IR: x{x} y{y} n{n}: xs{xs} r{r}");
+                    continue 'x;
+                }
+
+                //println!("NR: x{x} y{y} n{n}");
+            }
+
+            let freq = u64::try_from(x).unwrap() * u64::try_from(N_MAX).unwrap()
+                + u64::try_from(y).unwrap();
+            return Some(freq);
+        }
+    }
+
+    None
+}
+
+/// The `0..=n_max` square, at most [`COVERAGE_SIZE`] pixels wide: dark
+/// where no sensor reaches, lighter blue the more sensors cover a spot. Sensors
+/// are red, beacons yellow.
+fn coverage(data: &[Info], n_max: Ipos) -> Image {
+    let side = usize::try_from(n_max).unwrap().min(COVERAGE_SIZE - 1) + 1;
+    let last = i64::try_from(side - 1).unwrap().max(1);
+    let world =
+        |p: usize| Ipos::try_from(i64::try_from(p).unwrap() * i64::from(n_max) / last).unwrap();
+    let pixel = |v: Ipos| {
+        (0..=n_max)
+            .contains(&v)
+            .then(|| usize::try_from(i64::from(v) * last / i64::from(n_max.max(1))).unwrap())
+    };
+
+    let mut image = Image::new(side, side, [10, 10, 20]);
+    for py in 0..side {
+        for px in 0..side {
+            let spot = Point(world(px), world(py));
+            let covered = data
+                .iter()
+                .filter(|info| {
+                    info.sensor
+                        .distance(&spot)
+                        .is_some_and(|d| d <= info.distance)
+                })
+                .count();
+            if covered > 0 {
+                let blue = u8::try_from((80 + 35 * covered).min(255)).unwrap();
+                image.set(px, py, [30, 60, blue]);
+            }
+        }
+    }
+    for info in data {
+        for (point, color) in [(&info.beacon, [255, 220, 0]), (&info.sensor, [230, 40, 40])] {
+            if let (Some(x), Some(y)) = (pixel(point.0), pixel(point.1)) {
+                image.set(x, y, color);
+            }
+        }
+    }
+    image
+}
+
+/// `size` sensors, at least four. Four of them reach from beyond the corners
+/// of the part 2 search area to next to one spot in it, which is the only spot
+/// left out of reach. The others sit close to those four beacons, outside the
+/// area.
+#[must_use]
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let spot = Point(rng.random_range(0..=N_MAX), rng.random_range(0..N_MAX));
+    // Far enough to reach across the whole area from any spot.
+    let k = rng.random_range(N_MAX / 2 + 1..=N_MAX);
+    let mut placed: Vec<Info> = Vec::with_capacity(size.max(4));
+    for (ex, ey) in [(1, 1), (-1, 1), (-1, -1), (1, -1)] {
+        let sensor = Point(spot.0 + ex * k, spot.1 + ey * k);
+        // On the far side, out of reach of the other three.
+        let a = rng.random_range(0..2 * k);
+        let beacon = Point(sensor.0 + ex * a, sensor.1 + ey * (2 * k - 1 - a));
+        placed.push(Info {
+            sensor,
+            beacon,
+            distance: (2 * k - 1).unsigned_abs(),
+        });
+    }
+    while placed.len() < size {
+        let beacon = placed[rng.random_range(0..4)].beacon.clone();
+        let r = rng.random_range(1..=k / 2);
+        let dx = rng.random_range(-r..=r);
+        let dy = (r - dx.abs()) * if rng.random_bool(0.5) { 1 } else { -1 };
+        let sensor = Point(beacon.0 + dx, beacon.1 + dy);
+        if placed.iter().all(|other| other.sensor != sensor) {
+            placed.push(Info {
+                sensor,
+                beacon,
+                distance: r.unsigned_abs(),
+            });
+        }
+    }
+    placed.shuffle(rng);
+
+    let mut out = placed
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n");
+    out.push('\n');
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?, Y_ROW).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?, N_MAX).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn image(&self, input: &[u8]) -> Result<Option<Image>, ParseError> {
+        Ok(Some(coverage(&parse(input)?, N_MAX)))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{coverage, lines, parse, part1, part2, Info, Ipos, Point, N_MAX};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_point() {
+        assert_eq!(
+            Point::parse(b"x=9, y=16", b"x=9, y=16").unwrap(),
+            Point(9, 16)
+        );
+
+        assert_eq!(
+            Point::parse(b"x=-2413129, y=-12312216", b"x=-2413129, y=-12312216").unwrap(),
+            Point(-2_413_129, -12_312_216)
+        );
+
+        let e = parse(b"Sensor at x=9, y=16: closest beacon is at x=1O, y=16").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (45, "1O"));
+        let e = parse(b"Sensor at x=9 y=16: closest beacon is at x=10, y=16").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (11, "x=9 y=16"));
+        let e = parse(b"Sensor at x=-2147483648, y=0: closest beacon is at x=2147483647, y=9")
+            .unwrap_err();
+        assert_eq!(e.msg, "beacon too far from the sensor");
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut line = lines(INPUT);
+
+        assert_eq!(
+            parse(line.next().unwrap()).unwrap(),
+            vec![Info {
+                sensor: Point(2, 18),
+                beacon: Point(-2, 15),
+                distance: 7
+            }]
+        );
+
+        assert_eq!(
+            parse(line.next().unwrap()).unwrap(),
+            vec![Info {
+                sensor: Point(9, 16),
+                beacon: Point(10, 16),
+                distance: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let point = Point(0, 0);
+
+        assert_eq!(point.in_range(5, 5), Some(0));
+        assert_eq!(point.in_range(5, 0), Some(5));
+        assert_eq!(point.in_range(5, -5), Some(0));
+        assert_eq!(point.in_range(5, -1), Some(4));
+        assert_eq!(point.in_range(5, 6), None);
+        let point = Point(1_232_130, 1_231_230);
+        assert_eq!(point.in_range(5, 6), None);
+    }
+
+    #[test]
+    fn test_example() {
+        let data = parse(INPUT).unwrap();
+
+        let mut convert = Vec::<Ipos>::with_capacity(30);
+
+        for point in data {
+            let sensor = point.sensor;
+            let dis = point.distance;
+
+            if let Some(r) = sensor.in_range(dis, 10) {
+                let r = Ipos::try_from(r).unwrap();
+
+                for i in sensor.0 - r..sensor.0 + r {
+                    convert.push(i);
+                }
+            }
+
+            convert.sort_unstable();
+            convert.dedup();
+        }
+
+        assert_eq!(convert.len(), 26);
+
+        assert_eq!(part1(&parse(INPUT).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn test_coverage() {
+        let image = coverage(&parse(INPUT).unwrap(), 20);
+
+        assert_eq!((image.width(), image.height()), (21, 21));
+        assert_eq!(image.get(2, 18), Some([230, 40, 40]));
+        assert_eq!(image.get(2, 10), Some([255, 220, 0]));
+        assert_eq!(image.get(14, 11), Some([10, 10, 20]));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        const N_MAX: i32 = 20;
+
+        let data2 = parse(INPUT).unwrap();
+        'lus: for y in 0..N_MAX {
+            let mut x: i32 = -1;
+            'x: loop {
+                x += 1;
+                if x > N_MAX {
+                    continue 'lus;
+                }
+
+                for (n, point) in data2.iter().enumerate() {
+                    let sensor = &point.sensor;
+                    let dis = point.distance;
+
+                    if let Some(r) = sensor.in_range(dis, y) {                    
+                        let xs = sensor.0;
+                        // Not in range
+                        if x.abs_diff(xs) > r  {
+                            println!("ND: x{x} y{y} n{n}");
+                            continue;
+                        }
+                        
+                        x = x.max(xs - 1 + Ipos::try_from(r).unwrap());
+                        println!("IR: x{x} y{y} n{n}: xs{xs} r{r}");
+                        continue 'x;
+                    }
+
+                    println!("NR: x{x} y{y} n{n}");
+                }
+
+                println!("FOUND: x: {x} y: {y}");
+                break 'lus;
+            }
+        }
+
+        assert_eq!(part2(&parse(INPUT).unwrap(), N_MAX), Some(56_000_011));
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(15);
+        for size in [1, 12] {
+            let data = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(data.len(), size.max(4));
+            for info in &data {
+                assert!(data.iter().all(|other| other.beacon == info.beacon
+                    || info
+                        .sensor
+                        .distance(&other.beacon)
+                        .is_none_or(|d| d > info.distance)));
+            }
+
+            assert!(part2(&data, N_MAX).is_some());
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use aoc_common::{as_str, bfs, str_lines, Answer, ParseError, Paths, Solution};
+use log::debug;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::fmt::Write;
+
+type Score = u32;
+
+type Flow = u16;
+
+/// One bit per valve with flow, and the start.
+type Open = u128;
+
+/// Valves with flow that fit in [`Open`] next to the start.
+const MAX_FLOWING: usize = Open::BITS as usize - 1;
+
+#[derive(Debug)]
+struct Valve {
+    name: String,
+    flow: Flow,
+    /// Indices of the valves at the other end of the tunnels.
+    tunnels: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct Day16(Vec<Valve>);
+
+impl Day16 {
+    // Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+    fn from(input: &str) -> Result<Self, ParseError> {
+        let err =
+            |part: &str, msg: &str| ParseError::at_slice(input.as_bytes(), part.as_bytes(), msg);
+
+        let mut graph: Vec<Valve> = Vec::new();
+        let valve = |graph: &mut Vec<Valve>, name: &str| {
+            graph
+                .iter()
+                .position(|v| v.name == name)
+                .unwrap_or_else(|| {
+                    graph.push(Valve {
+                        name: name.to_string(),
+                        flow: 0,
+                        tunnels: Vec::new(),
+                    });
+                    graph.len() - 1
+                })
+        };
+        let mut defined = Vec::new();
+        let mut tunnels = Vec::new();
+        let mut flowing = 0;
+
+        for line in str_lines(input) {
+            let syntax = || {
+                err(
+                    line,
+                    "expected `Valve XX has flow rate=N; tunnels lead to valves ..`",
+                )
+            };
+            let mut words = line.split(" ");
+
+            if words.next() != Some("Valve") {
+                return Err(syntax());
+            }
+            let node_name = words.next().ok_or_else(syntax)?;
+            defined.push(node_name);
+
+            let rate = words
+                .nth(2)
+                .and_then(|w| w.strip_prefix("rate="))
+                .and_then(|w| w.strip_suffix(';'))
+                .ok_or_else(syntax)?;
+            let rate = rate
+                .parse::<u8>()
+                .map_err(|_| err(rate, "expected a flow rate"))?
+                .into();
+            if rate > 0 {
+                flowing += 1;
+                if flowing > MAX_FLOWING {
+                    return Err(err(
+                        line,
+                        &format!("more than {MAX_FLOWING} valves with flow"),
+                    ));
+                }
+            }
+
+            let valves: Vec<&str> = words.skip(4).map(|w| w.trim_end_matches(',')).collect();
+            if valves.is_empty() {
+                return Err(err(&line[line.len()..], "expected a tunnel"));
+            }
+            tunnels.extend_from_slice(&valves);
+
+            //println!("{node_name:?}, {rate}, {valves:?}");
+
+            let from_idx = valve(&mut graph, node_name);
+            graph[from_idx].flow = rate;
+
+            // Tunnels go both ways.
+            for node_to in valves {
+                let to_idx = valve(&mut graph, node_to);
+                graph[from_idx].tunnels.push(to_idx);
+                graph[to_idx].tunnels.push(from_idx);
+            }
+        }
+
+        if let Some(valve) = tunnels.iter().find(|v| !defined.contains(v)) {
+            return Err(err(valve, "unknown valve"));
+        }
+        if !defined.contains(&"AA") {
+            return Err(err(&input[input.len()..], "no valve AA"));
+        }
+        Ok(Self(graph))
+    }
+
+    /// Distances from the start and from every valve with flow that can be
+    /// reached from it.
+    fn searches(&self, start_idx: usize) -> Vec<Search> {
+        let start = bfs(start_idx, |&v| self.0[v].tunnels.iter().copied(), |_| false);
+        (0..self.0.len())
+            .filter(|&n| n == start_idx || self.0[n].flow > 0 && start.distance(&n).is_some())
+            .map(|n| Search {
+                node: n,
+                distance: bfs(n, |&v| self.0[v].tunnels.iter().copied(), |_| false),
+                flow: self.0[n].flow as u8,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Search {
+    node: usize,
+    distance: Paths<usize, usize>,
+    flow: u8,
+}
+
+impl Search {
+    fn distance(&self, to: usize) -> Score {
+        Score::try_from(self.distance.distance(&to).unwrap()).unwrap()
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+fn rec(data: &Vec<Search>, values_open: Open, prev: usize, minutes: u32) -> (Score, Open) {
+    let prev_dis = data.get(prev).unwrap();
+
+    let mut score: Score = 0;
+    for (bit_idx, node) in data.iter().enumerate() {
+        let bit: Open = 1 << bit_idx;
+        if values_open & bit != 0 {
+            continue;
+        }
+        let distance = prev_dis.distance(node.node);
+        let Some(rem_minutes) = minutes.checked_sub(distance + 1) else { continue;} ;
+        score = score.max(
+            rec(data, values_open | bit, bit_idx, rem_minutes).0 + (node.flow as u32 * rem_minutes),
+        );
+    }
+
+    (score, values_open)
+}
+
+fn rec2(data: &Vec<Search>, values_open: Open, prev: [usize; 2], minutes: [u32; 2]) -> (Score, Open) {
+    let player = if minutes[0] >= minutes[1] {
+        0
+    } else {
+        1
+    };
+    let prev_dis = data.get(prev[player]).unwrap();
+
+    let mut score: Score = 0;
+    for (bit_idx, node) in data.iter().enumerate() {
+        let bit: Open = 1 << bit_idx;
+        if values_open & bit != 0 {
+            continue;
+        }
+        let distance = prev_dis.distance(node.node);
+        let Some(rem_minutes) = minutes[player].checked_sub(distance + 1) else { continue;};
+        let mut ni = prev;
+        ni[player] = bit_idx;
+        let mut rm = minutes;
+        rm[player] = rem_minutes;
+        score = score.max(
+            rec2(
+                data,
+                values_open | bit,
+                ni,
+                rm,
+            ).0 + (node.flow as u32 * rem_minutes),
+        );
+    }
+
+    (score, values_open)
+}
+
+
+pub fn parse(input: &str) -> Result<Day16, ParseError> {
+    Day16::from(input)
+}
+
+pub fn part1(graph: &Day16) -> Score {
+    // let mut f = std::fs::File::create("graph.dot").unwrap();
+    // f.write_all(format!("{:?}", Dot::with_config(&graph.0, &[])).as_bytes())
+    //     .unwrap();
+
+    let start_idx = graph.0.iter().position(|n| n.name == "AA").unwrap();
+    let s = graph.searches(start_idx);
+
+    debug!("combi {}, {}", s.len(), factorial(s.len() - 1));
+
+    rec(
+        &s,
+        0,
+        s.iter().position(|n| n.node == start_idx).unwrap(),
+        30,
+    )
+    .0
+}
+
+pub fn part2(graph: &Day16) -> Score {
+    // let mut f = std::fs::File::create("graph.dot").unwrap();
+    // f.write_all(format!("{:?}", Dot::with_config(&graph.0, &[])).as_bytes())
+    //     .unwrap();
+
+    let start_idx = graph.0.iter().position(|n| n.name == "AA").unwrap();
+    let s = graph.searches(start_idx);
+
+    debug!("combi {}, {}", s.len(), factorial(s.len() - 1));
+
+    let si = s.iter().position(|n| n.node == start_idx).unwrap();
+
+    let (score, _valve) = rec2(&s, 0, [si;2 ], [26; 2]);
+
+    score
+}
+
+fn factorial(n: usize) -> usize {
+    let mut ret: usize = 1;
+    for n in 1..n {
+        ret = ret.saturating_mul(n);
+    }
+    ret
+}
+
+/// Scan of connected valves, `size` of them with flow and three times as many
+/// without. The start valve `AA` is stuck.
+///
+/// The search tries every order of the valves with flow. Part 2 takes minutes
+/// from about 50 of them, and [`parse`] takes at most 127.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let count = size.saturating_mul(4).clamp(2, 26 * 26);
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", char::from(a), char::from(b))))
+        .skip(1)
+        .collect();
+    names.shuffle(rng);
+    names.insert(0, "AA".to_owned());
+
+    // A random tree keeps every valve reachable, a few more tunnels add loops.
+    let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.random_range(0..valve));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.random_range(0..count), rng.random_range(0..count));
+    }
+
+    let mut flows = vec![0; count];
+    let mut order: Vec<usize> = (1..count).collect();
+    order.shuffle(rng);
+    for &valve in order.iter().take(size.max(1)) {
+        flows[valve] = rng.random_range(1..=25);
+    }
+
+    let mut valves: Vec<usize> = (0..count).collect();
+    valves.shuffle(rng);
+    let mut out = String::new();
+    for valve in valves {
+        let to: Vec<&str> = tunnels[valve].iter().map(|&v| names[v].as_str()).collect();
+        let tunnels = if to.len() == 1 {
+            "tunnel leads to valve"
+        } else {
+            "tunnels lead to valves"
+        };
+        writeln!(
+            out,
+            "Valve {} has flow rate={}; {tunnels} {}",
+            names[valve],
+            flows[valve],
+            to.join(", ")
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2};
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+const INPUT_B: &str = include_str!("../input/example2.txt");
+
+
+    #[test]
+    fn score() {
+        let score = part1(&parse(INPUT).unwrap());
+
+        println!("Score: {score}");
+
+        assert_eq!(score, 1651);
+
+        let score = part1(&parse(INPUT_B).unwrap());
+
+        println!("Score: {score}");
+
+        assert_eq!(score, 1651);
+    }
+
+    #[test]
+    fn score2() {
+        let score = part2(&parse(INPUT).unwrap());
+
+        println!("Score: {score}");
+
+        assert_eq!(score, 1707);
+    }
+
+    #[test]
+    fn parse_error() {
+        let e = parse(&INPUT.replace("rate=13", "rate=x")).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 24, "x"));
+        let e = parse(&INPUT.replace("Valve AA", "Valve ZZ")).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 55, "AA"));
+        let e = parse(&INPUT.replace("AA", "ZZ")).unwrap_err();
+        assert_eq!(e.msg, "no valve AA");
+
+        let many: String = (0..128)
+            .map(|n| format!("Valve A{n} has flow rate=1; tunnel leads to valve AA\n"))
+            .collect();
+        let e = parse(&format!(
+            "Valve AA has flow rate=0; tunnel leads to valve AA\n{many}"
+        ))
+        .unwrap_err();
+        assert_eq!(
+            (e.line, e.msg.as_str()),
+            (129, "more than 127 valves with flow")
+        );
+    }
+
+    #[test]
+    fn many_flowing() {
+        // More valves with flow than a u16 has bits, in a line at the end of a
+        // corridor that leaves time for two of them.
+        let mut input = "Valve AA has flow rate=0; tunnels lead to valves B0\n".to_owned();
+        let names: Vec<_> = (0..25)
+            .map(|n| format!("B{n}"))
+            .chain((0..17).map(|n| format!("A{n}")))
+            .collect();
+        for (n, name) in names.iter().enumerate() {
+            let (flow, prev) = match n {
+                0 => (0, "AA"),
+                _ => (u8::from(n >= 25), names[n - 1].as_str()),
+            };
+            input += &format!("Valve {name} has flow rate={flow}; tunnels lead to valves {prev}\n");
+        }
+        let graph = parse(&input).unwrap();
+        assert_eq!(part1(&graph), 3 + 1);
+    }
+
+    #[test]
+    fn unreachable() {
+        // CC and DD only lead to each other.
+        let graph = parse(
+            "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=5; tunnel leads to valve AA
+Valve CC has flow rate=9; tunnel leads to valve DD
+Valve DD has flow rate=7; tunnel leads to valve CC
+",
+        )
+        .unwrap();
+        assert_eq!(part1(&graph), 5 * 28);
+        assert_eq!(part2(&graph), 5 * 24);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(16);
+        for size in [1, 3, 8] {
+            let graph = parse(&generate(&mut rng, size)).unwrap();
+            assert_eq!(graph.0.len(), (size * 4).max(2));
+            assert_eq!(graph.0.iter().filter(|v| v.flow > 0).count(), size);
+            assert!(part1(&graph) > 0);
+            assert!(part2(&graph) > 0);
+        }
+
+        // Past what a u16 mask of open valves holds.
+        let graph = parse(&generate(&mut rng, 16)).unwrap();
+        assert!(part1(&graph) > 0);
+
+        let e = parse(&generate(&mut rng, 128)).unwrap_err();
+        assert_eq!(e.msg, "more than 127 valves with flow");
+    }
+}
@@ -0,0 +1,693 @@
+use aoc_common::{
+    lines, text_grid, Answer, Frame, Grid, Loc, ParseError, Part, Snapshot, Solution,
+};
+use log::debug;
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+const FIELD_WIDTH: usize = 7;
+/// Rows the field starts with, and grows by when the tower reaches the top.
+const FIELD_HEIGHT: usize = 10 * 1024;
+
+const FIELD_PART2: u64 = 1_000_000_000_000;
+/// Rocks simulated before looking for the repeating pattern in part 2.
+const ROCKS_PART2: u32 = 6000;
+
+#[allow(dead_code)]
+mod pos {
+    pub const EMPTY: u8 = b'.';
+    pub const BLOCK: u8 = b'#';
+    pub const SAND: u8 = b'O';
+    pub const START: u8 = b'+';
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct State {
+    height: u32,
+    blocks: u32,
+}
+
+/// The jet pattern, the first line of the input.
+pub fn parse(input: &[u8]) -> Result<&[u8], ParseError> {
+    let mut line = lines(input);
+
+    let jets = line.next().unwrap_or_default();
+    if jets.is_empty() {
+        return Err(ParseError::at(input, 0, "expected a jet pattern"));
+    }
+    match jets.iter().position(|c| *c != b'<' && *c != b'>') {
+        Some(p) => Err(ParseError::at_slice(
+            input,
+            &jets[p..=p],
+            "expected `<` or `>`",
+        )),
+        None => Ok(jets),
+    }
+}
+
+fn rock_count(part: Part) -> u32 {
+    match part {
+        Part::One => 2022,
+        Part::Two => ROCKS_PART2,
+    }
+}
+
+/// The answer of `part` once all its rocks have fallen.
+fn answer(sim: &PlayField, part: Part) -> Answer {
+    match part {
+        Part::One => sim.max().into(),
+        Part::Two => extrapolate(sim).map_or(Answer::Unsolved, Answer::from),
+    }
+}
+
+pub fn part1(jets: &[u8]) -> usize {
+    rocks(jets, 2022).last().map_or(0, |rock| rock.height)
+}
+
+/// Tower height after [`FIELD_PART2`] rocks, extrapolated from the repeating
+/// pattern. `None` when no pattern shows up in the first rocks.
+pub fn part2(jets: &[u8]) -> Option<u64> {
+    let mut sim = PlayField::new(jets);
+
+    sim.sim(ROCKS_PART2);
+
+    extrapolate(&sim)
+}
+
+fn extrapolate(sim: &PlayField) -> Option<u64> {
+    let (line, step) = sim.detect_pattern()?;
+
+    let (start_block, blocks) = 'block_search: {
+        for i in 0..sim.state.len() {
+            let start_b = &sim.state[i];
+            if (start_b.height as usize) < line {
+                continue;
+            }
+            for j in i + 1..sim.state.len() {
+                let search = &sim.state[j];
+                if Shapes::from(start_b.blocks) == Shapes::from(search.blocks)
+                    && start_b.height + u32::try_from(step).unwrap() == search.height
+                {
+                    break 'block_search Some((start_b.blocks, search.blocks - start_b.blocks));
+                }
+            }
+        }
+        None
+    }?;
+
+    let n = (FIELD_PART2 - start_block as u64) / (blocks as u64);
+
+    let repeat = n * blocks as u64;
+
+    let remain = FIELD_PART2 - start_block as u64 - repeat;
+
+    let heigth_end = sim
+        .state
+        .iter()
+        .find(|s| (s.blocks + 1) as u64 >= start_block as u64 + remain)
+        .map(|s| s.height)?;
+
+    Some(n * step as u64 + heigth_end as u64)
+}
+
+/// The first `count` rocks falling through `jets`, a pattern that [`parse`]
+/// accepted. One item per rock that comes to rest.
+pub fn rocks(jets: &[u8], count: u32) -> Rocks {
+    Rocks {
+        sim: PlayField::new(jets),
+        count,
+    }
+}
+
+/// The tower after a rock came to rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rock {
+    /// Rocks at rest, this one included.
+    pub fallen: u32,
+    pub height: usize,
+}
+
+/// See [`rocks`].
+pub struct Rocks {
+    sim: PlayField,
+    count: u32,
+}
+
+impl Iterator for Rocks {
+    type Item = Rock;
+
+    fn next(&mut self) -> Option<Rock> {
+        if self.sim.blocks >= self.count {
+            return None;
+        }
+        self.sim.sim(self.sim.blocks + 1);
+        Some(Rock {
+            fallen: self.sim.blocks,
+            height: self.sim.max(),
+        })
+    }
+}
+
+/// Jet pattern of `size` pushes, at least 40.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut jets: String = (0..size.max(40))
+        .map(|_| if rng.random_bool(0.5) { '<' } else { '>' })
+        .collect();
+    jets.push('\n');
+    jets
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn animate(
+        &self,
+        part: Part,
+        input: &[u8],
+        draw: &mut dyn FnMut(&Frame),
+    ) -> Result<Answer, ParseError> {
+        let mut rocks = rocks(parse(input)?, rock_count(part));
+        while let Some(rock) = rocks.next() {
+            draw(
+                rocks
+                    .sim
+                    .frame()
+                    .caption(format!("rock {}, height {}", rock.fallen, rock.height)),
+            );
+        }
+        Ok(answer(&rocks.sim, part))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    /// Steps are rocks.
+    fn snapshot(
+        &self,
+        part: Part,
+        input: &[u8],
+        steps: usize,
+    ) -> Result<Option<String>, ParseError> {
+        let mut sim = PlayField::new(parse(input)?);
+        let step = u32::try_from(steps).map_or(rock_count(part), |s| s.min(rock_count(part)));
+        if step > 0 {
+            sim.sim(step);
+        }
+        let snapshot = Snapshot {
+            day: 17,
+            part,
+            step: step as usize,
+            state: sim,
+        };
+        Ok(Some(snapshot.to_json()))
+    }
+
+    fn resume(&self, snapshot: &str) -> Result<Option<Answer>, String> {
+        let Snapshot {
+            part,
+            state: mut sim,
+            ..
+        } = Snapshot::<PlayField>::from_json(snapshot, 17)?;
+        sim.check()?;
+        if sim.blocks < rock_count(part) {
+            sim.sim(rock_count(part));
+        }
+        Ok(Some(answer(&sim, part)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+enum Shapes {
+    #[default]
+    Min,
+    Plus,
+    RightAngle,
+    Pipe,
+    Block,
+}
+
+impl Shapes {
+    fn from(blocks: u32) -> Self {
+        match blocks % 5 {
+            0 => Shapes::Min,
+            1 => Shapes::Plus,
+            2 => Shapes::RightAngle,
+            3 => Shapes::Pipe,
+            4 => Shapes::Block,
+            e => panic!("Unknown block {e}"),
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        match self {
+            Shapes::Min => Shape(vec![(0, 1), (0, 1), (0, 1), (0, 1)]),
+            Shapes::Plus => Shape(vec![(1, 1), (0, 3), (1, 1)]),
+            Shapes::RightAngle => Shape(vec![(0, 1), (0, 1), (0, 3)]),
+            Shapes::Pipe => Shape(vec![(0, 4)]),
+            Shapes::Block => Shape(vec![(0, 2), (0, 2)]),
+        }
+    }
+}
+
+struct Shape(Vec<(u8, u8)>);
+
+#[derive(Serialize, Deserialize)]
+struct PlayField {
+    jets: String,
+    step: usize,
+    #[serde(with = "text_grid")]
+    field: Grid<u8>,
+    blocks: u32,
+    height: usize,
+    /// Heigth, Block, Shape
+    state: Vec<State>,
+}
+
+impl PlayField {
+    /// `jets` is a pattern that [`parse`] accepted.
+    fn new(jets: &[u8]) -> Self {
+        Self {
+            jets: String::from_utf8_lossy(jets).into_owned(),
+            step: 0,
+            field: Grid::new(FIELD_WIDTH, FIELD_HEIGHT, pos::EMPTY),
+            blocks: 0,
+            height: 0,
+            state: Vec::with_capacity(FIELD_HEIGHT),
+        }
+    }
+
+    #[cfg(test)]
+    fn colom_height(&self, n: usize) -> usize {
+        for pos in (0..self.height).rev() {
+            if self.field[Loc::new(n, pos)] == pos::BLOCK {
+                return pos + 1;
+            }
+        }
+        0
+    }
+
+    #[cfg(test)]
+    fn len_map(&self) -> Vec<usize> {
+        let mut len = Vec::<usize>::with_capacity(FIELD_WIDTH);
+        for n in 0..FIELD_WIDTH {
+            len.push(self.colom_height(n));
+        }
+        len
+    }
+
+    fn max(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a state from a snapshot can be simulated further.
+    fn check(&self) -> Result<(), String> {
+        let jets = self.jets.as_bytes();
+        if parse(jets).ok() != Some(jets) || self.step >= jets.len() {
+            return Err("bad jet pattern".to_owned());
+        }
+        if self.field.width() != FIELD_WIDTH
+            || self.field.height() < FIELD_HEIGHT
+            || !self.field.height().is_multiple_of(FIELD_HEIGHT)
+        {
+            return Err(format!(
+                "field is not {FIELD_WIDTH} wide in steps of {FIELD_HEIGHT} rows"
+            ));
+        }
+        if self.height > self.field.height() {
+            return Err(format!("height {} is outside the field", self.height));
+        }
+        let top = (0..self.field.height())
+            .rposition(|y| self.field.row(y).contains(&pos::BLOCK));
+        if top.map_or(0, |y| y + 1) != self.height {
+            return Err(format!(
+                "height {} is not the top of the tower",
+                self.height
+            ));
+        }
+        let blocks = self.state.iter().zip(0..).all(|(s, n)| s.blocks == n);
+        let heights = self.state.windows(2).all(|w| w[0].height <= w[1].height);
+        let last = self.state.last().map_or(0, |s| s.height as usize);
+        if self.state.len() != self.blocks as usize || !blocks || !heights || last != self.height {
+            return Err("state does not match the rocks in the field".to_owned());
+        }
+        Ok(())
+    }
+
+    /// The top of the tower, up is up.
+    fn frame(&self) -> Frame {
+        const ROWS: usize = 100;
+
+        let mut frame = Frame::new("");
+        let top = self.height + 3;
+        let bottom = top.saturating_sub(ROWS);
+        for row in bottom..top {
+            let y = -(row as i64);
+            frame.set(-1, y, '|').set(FIELD_WIDTH, y, '|');
+            for x in 0..FIELD_WIDTH {
+                if self.field.get(Loc::new(x, row)) == Some(&pos::BLOCK) {
+                    frame.set(x, y, '#');
+                }
+            }
+        }
+        if bottom == 0 {
+            frame.set(-1, 1, '+').set(FIELD_WIDTH, 1, '+');
+            for x in 0..FIELD_WIDTH {
+                frame.set(x, 1, '-');
+            }
+        }
+        frame.focus(0, -(top as i64));
+        frame
+    }
+
+    fn sim(&mut self, blocks: u32) {
+        let mut shape = Shapes::from(self.blocks).shape();
+        let mut x = 2;
+        let mut max = self.height;
+        let mut row = max + 4;
+        if self.field.height() < row + 4 {
+            self.field.push_rows(FIELD_HEIGHT, pos::EMPTY);
+        }
+
+        loop {
+            // Ended?
+            let mut new_block = row == 0;
+            //if max >= row
+            if !new_block {
+                for (egde, col) in shape.0.iter().zip(x..) {
+                    // println!("--- Step: {} Top {}, Row {row} edge ({},{}) ", self.step, top, egde.0, egde.1);
+                    //if row + usize::from(egde.0) <= top.len() {
+                    if let Some(&pos::BLOCK) =
+                        self.field.get(Loc::new(col, row + usize::from(egde.0) - 1))
+                    {
+                        new_block = true;
+                        break;
+                    }
+                }
+            }
+
+            //println!("#### Step: {}, Row: {row}, X {x}, blocks {}, new_block {}", self.step, self.blocks, new_block);
+
+            if new_block {
+                for (egde, col) in shape.0.iter().zip(x..) {
+                    for n in egde.0..egde.0 + egde.1 {
+                        self.field[Loc::new(col, row + usize::from(n))] = b'#';
+                        self.height = self.height.max(row + usize::from(n) + 1);
+                    }
+                    // println!("Step: {} Top {}, Row {row} edge ({},{}) ", self.step, *top, egde.0, egde.1);
+                }
+
+                self.state.push( State { height: self.height as u32, blocks: self.blocks});
+
+                self.blocks += 1;
+                // Done?
+                if self.blocks == blocks {
+                    break;
+                }
+
+                // Next block
+                shape = Shapes::from(self.blocks).shape();
+                x = 2;
+                max = self.height;
+                row = max + 4;
+                if self.field.height() < row + 4 {
+                    self.field.push_rows(FIELD_HEIGHT, pos::EMPTY);
+                }
+            }
+
+            // Get direction
+            let dir = self.jets.as_bytes()[self.step];
+
+            let new_x: Option<usize> = match dir {
+                b'<' => {
+                    if x != 0 {
+                        Some(x - 1)
+                    } else {
+                        None
+                    }
+                }
+                b'>' => {
+                    if x + shape.0.len() < FIELD_WIDTH {
+                        Some(x + 1)
+                    } else {
+                        None
+                    }
+                }
+                e => panic!("Unknown Move {e}"),
+            };
+
+            // print!("\tCan move X {x} -> {new_x:?}: ");
+
+            if let Some(new_x) = new_x {
+                let mut can_move = true;
+
+                for (edge, col) in shape.0.iter().zip(new_x..) {
+                    for n in edge.0..edge.0 + edge.1 {
+                        if let Some(b'#') = self.field.get(Loc::new(col, row + usize::from(n) - 1))
+                        {
+                            can_move = false;
+                            break;
+                        }
+                    }
+                }
+
+                if can_move {
+                    //println!("Moved");
+                    x = new_x
+                } else {
+                    //println!("Block");
+                }
+            } else {
+                //println!("Edge!");
+            }
+
+            // Next row
+            row -= 1;
+            self.step += 1;
+            if self.step == self.jets.len() {
+                self.step = 0;
+            }
+        }
+    }
+
+    fn detect_pattern(&self) -> Option<(usize, usize)> {
+        let mut list = Vec::<u16>::with_capacity(self.height);
+
+        let mut state = self.state.iter(); 
+
+        let mut shape = state.next().unwrap();
+
+        for i in 0..self.height {
+            let mut top_row = 0;
+
+            for (b, &c) in self.field.row(i).iter().enumerate() {
+                if c == pos::BLOCK {
+                    top_row |= 1 << b;
+                }
+            }
+            if usize::try_from(shape.height).unwrap() == i {
+                shape = state.next().unwrap();
+            }
+
+            top_row |= u16::try_from(shape.blocks % 5).unwrap() << 8;
+            list.push(top_row);
+        }
+
+        for step in 6..list.len() {
+            for line in 1..list.len() - step {
+                let mut found = 0;
+
+                'line: for start in (line..list.len()).step_by(step).skip(1) {
+                    for (num, search) in (start..(start + step).min(self.height)).enumerate() {
+                        if list[line + num] != list[search] {
+                            break 'line;
+                        }
+                    }
+                    found += 1;
+
+                    if found == ((self.height - line) / step) - 1 {
+                        debug!("### Line: {line} step {step} Found {found}");
+                        return Some((line, step));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, rocks, Loc, PlayField, Rock, Solver};
+    use aoc_common::{Answer, Part, Solution};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_draw_example() {
+        let mut sim = PlayField::new(parse(INPUT).unwrap());
+
+        sim.sim(1);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.len_map(), [0, 0, 1, 1, 1, 1, 0]);
+
+        sim.sim(2);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.len_map(), [0, 0, 3, 4, 3, 1, 0]);
+
+        sim.sim(3);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.len_map(), [4, 4, 6, 4, 3, 1, 0]);
+
+        sim.sim(8);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.len_map(), [4, 12, 13, 13, 13, 15, 0]);
+
+        sim.sim(9);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.len_map(), [4, 12, 13, 13, 17, 15, 0]);
+
+        sim.sim(10);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.len_map(), [14, 14, 13, 13, 17, 15, 0]);
+    }
+
+    #[test]
+    fn test_rocks() {
+        let jets = parse(INPUT).unwrap();
+        let heights: Vec<_> = rocks(jets, 4).map(|rock| rock.height).collect();
+        assert_eq!(heights, [1, 4, 6, 7]);
+
+        let last = Rock {
+            fallen: 2022,
+            height: 3068,
+        };
+        assert_eq!(rocks(jets, 2022).last(), Some(last));
+        assert_eq!(rocks(jets, 0).next(), None);
+    }
+
+    #[test]
+    fn test_example() {
+        let mut sim = PlayField::new(parse(INPUT).unwrap());
+
+        sim.sim(2022);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.max(), 3068);
+
+        assert_eq!(sim.detect_pattern(), Some((25, 53)));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(super::part2(parse(INPUT).unwrap()), Some(1_514_285_714_288));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(super::parse(b">><\n"), Ok(&b">><"[..]));
+        let e = super::parse(b">>-<").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (3, "-"));
+    }
+
+    #[test]
+    fn test_shift_fit() {
+        let mut sim = PlayField::new(b"<<<<>>");
+
+        sim.field[Loc::new(5, 1)] = b'#';
+        sim.field[Loc::new(6, 0)] = b'#';
+        sim.field[Loc::new(6, 1)] = b'#';
+
+        sim.height = 2;
+
+        assert_eq!(sim.len_map(), [0, 0, 0, 0, 0, 2, 2]);
+
+        sim.sim(1);
+
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.max(), 2);
+
+        assert_eq!(sim.len_map(), [0, 0, 1, 1, 1, 2, 2]);
+
+        for state in sim.state.iter() {
+            println!("{:?}", state);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::{generate, part1, part2};
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        for seed in 0..8 {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            for size in [1, 40, 200, 1000] {
+                let input = generate(&mut rng, size);
+                let jets = parse(input.as_bytes()).unwrap();
+                assert_eq!(jets.len(), size.max(40));
+                assert!(part1(jets) <= 4 * 2022);
+                let tower = part2(jets).unwrap_or_else(|| panic!("seed {seed}, size {size}"));
+                assert!(tower > part1(jets) as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_snapshot() {
+        for (part, steps, answer) in [
+            (Part::One, 0, 3068),
+            (Part::One, 1000, 3068),
+            (Part::One, 5000, 3068),
+            (Part::Two, 3000, 1514285714288),
+        ] {
+            let json = Solver.snapshot(part, INPUT, steps).unwrap().unwrap();
+            assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(answer))));
+        }
+
+        let json = Solver.snapshot(Part::One, b"<>", 10).unwrap().unwrap();
+        let json = json.replace("\"<>\"", "\"<x\"");
+        assert_eq!(Solver.resume(&json), Err("bad jet pattern".to_owned()));
+
+        let json = Solver.snapshot(Part::One, INPUT, 10).unwrap().unwrap();
+        let json = json.replacen("\"height\": 17,", "\"height\": 99999,", 1);
+        assert_eq!(
+            Solver.resume(&json),
+            Err("height 99999 is outside the field".to_owned())
+        );
+        let json = json.replacen("\"height\": 99999,", "\"height\": 16,", 1);
+        assert_eq!(
+            Solver.resume(&json),
+            Err("height 16 is not the top of the tower".to_owned())
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use aoc_common::{bfs, lines, try_slice_to_number, Answer, ParseError, Solution};
+use log::debug;
+use rand::{Rng, RngCore};
+use std::{collections::HashSet, fmt::Write};
+
+type Coord = i8;
+
+type Cube = [Coord; 3];
+
+/// Coordinates stay below this, it keeps the flood fill of part 2 small.
+const MAX: Coord = 64;
+
+const SIDES: [Cube; 6] = [
+    [1, 0, 0],
+    [-1, 0, 0],
+    [0, 1, 0],
+    [0, -1, 0],
+    [0, 0, 1],
+    [0, 0, -1],
+];
+
+/// Cubes of the lava droplet, one `x,y,z` per line.
+pub fn parse(input: &[u8]) -> Result<Droplet, ParseError> {
+    Droplet::new(input)
+}
+
+/// Sides of the cubes that touch no other cube.
+pub fn part1(droplet: &Droplet) -> usize {
+    droplet
+        .cubes
+        .iter()
+        .flat_map(neighbours)
+        .filter(|c| !droplet.cubes.contains(c))
+        .count()
+}
+
+/// Sides that the steam around the droplet reaches, air pockets inside do not
+/// count.
+pub fn part2(droplet: &Droplet) -> usize {
+    let (min, max) = droplet.bounds();
+    let inside = |c: &Cube| (0..3).all(|a| (min[a]..=max[a]).contains(&c[a]));
+    let steam = bfs(
+        min,
+        |c| {
+            neighbours(c)
+                .filter(|n| inside(n) && !droplet.cubes.contains(n))
+                .collect::<Vec<_>>()
+        },
+        |_| false,
+    );
+    debug!("{} cubes of steam", steam.distances().count());
+
+    droplet
+        .cubes
+        .iter()
+        .flat_map(neighbours)
+        .filter(|c| steam.distance(c).is_some())
+        .count()
+}
+
+fn neighbours(&[x, y, z]: &Cube) -> impl Iterator<Item = Cube> {
+    SIDES
+        .iter()
+        .map(move |[dx, dy, dz]| [x + dx, y + dy, z + dz])
+}
+
+/// A random walk of `size` cubes in a 22 cube box, like the puzzle input. It
+/// clumps together and encloses some air.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    const SIDE: Coord = 22;
+
+    let count = size.clamp(1, 5000);
+    let mut cube = [SIDE / 2; 3];
+    let mut seen = HashSet::with_capacity(count);
+    let mut out = String::new();
+    while seen.len() < count {
+        if seen.insert(cube) {
+            let [x, y, z] = cube;
+            writeln!(out, "{x},{y},{z}").unwrap();
+        }
+        let axis = rng.random_range(0..3);
+        let step = if rng.random_bool(0.5) { 1 } else { -1 };
+        cube[axis] = (cube[axis] + step).clamp(0, SIDE - 1);
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Droplet {
+    cubes: HashSet<Cube>,
+}
+
+impl Droplet {
+    fn new(input: &[u8]) -> Result<Self, ParseError> {
+        let mut cubes = HashSet::new();
+
+        for line in lines(input) {
+            let mut cube = [0; 3];
+            for (c, coord) in cube.iter_mut().zip(line.split(|&c| c == b',')) {
+                *c = try_slice_to_number::<u8>(coord)
+                    .and_then(|n| Coord::try_from(n).ok())
+                    .filter(|&n| n < MAX)
+                    .ok_or_else(|| {
+                        let msg = format!("expected a number below {MAX}");
+                        ParseError::at_slice(input, coord, msg)
+                    })?;
+            }
+            if line.iter().filter(|&&c| c == b',').count() != 2 {
+                return Err(ParseError::at_slice(input, line, "expected `x,y,z`"));
+            }
+            cubes.insert(cube);
+        }
+
+        if cubes.is_empty() {
+            return Err(ParseError::at(input, input.len(), "expected a cube"));
+        }
+        Ok(Self { cubes })
+    }
+
+    /// Corners of a box with a layer of air around the droplet.
+    fn bounds(&self) -> (Cube, Cube) {
+        let mut min = [Coord::MAX; 3];
+        let mut max = [Coord::MIN; 3];
+        for cube in &self.cubes {
+            for a in 0..3 {
+                min[a] = min[a].min(cube[a] - 1);
+                max[a] = max[a].max(cube[a] + 1);
+            }
+        }
+        (min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_parse() {
+        let droplet = parse(INPUT).unwrap();
+        assert_eq!(droplet.cubes.len(), 13);
+        assert!(droplet.cubes.contains(&[2, 2, 6]));
+        assert_eq!(droplet.bounds(), ([0, 0, 0], [4, 4, 7]));
+
+        let e = parse(b"1,1,1\n2,x,1").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+        let e = parse(b"1,1,1\n2,1").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (2, "expected `x,y,z`"));
+        let e = parse(b"1,1,1,1").unwrap_err();
+        assert_eq!(e.msg, "expected `x,y,z`");
+        assert_eq!(parse(b"1,64,1").unwrap_err().text, "64");
+        assert_eq!(parse(b"").unwrap_err().msg, "expected a cube");
+    }
+
+    #[test]
+    fn test_example() {
+        let two = parse(b"1,1,1\n2,1,1\n").unwrap();
+        assert_eq!((part1(&two), part2(&two)), (10, 10));
+
+        let droplet = parse(INPUT).unwrap();
+        assert_eq!(part1(&droplet), 64);
+        assert_eq!(part2(&droplet), 58);
+    }
+
+    #[test]
+    fn test_pocket() {
+        // A 3x3x3 block without its middle cube.
+        let mut input = String::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    if (x, y, z) != (1, 1, 1) {
+                        input += &format!("{x},{y},{z}\n");
+                    }
+                }
+            }
+        }
+        let droplet = parse(input.as_bytes()).unwrap();
+        assert_eq!(part1(&droplet), 54 + 6);
+        assert_eq!(part2(&droplet), 54);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(18);
+        for size in [1, 50, 2000] {
+            let droplet = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(droplet.cubes.len(), size);
+            assert!(part2(&droplet) <= part1(&droplet));
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use aoc_common::{as_str, str_lines, Answer, ParseError, Solution};
+use log::debug;
+use rand::{Rng, RngCore};
+use std::fmt::Write;
+
+type Score = u32;
+
+type Count = u16;
+
+/// Resources and robots go ore, clay, obsidian, geode.
+const ORE: usize = 0;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+/// `#` marks the numbers, each followed by the rest of its word.
+const TEMPLATE: &str = "Blueprint #: Each ore robot costs # ore. Each clay robot costs # ore. \
+    Each obsidian robot costs # ore and # clay. Each geode robot costs # ore and # obsidian.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    id: Score,
+    /// Ore, clay and obsidian for each kind of robot.
+    costs: [[Count; 3]; 4],
+    /// Most of each resource a single robot costs, more robots of that kind
+    /// than this can't be put to use.
+    most: [Count; 3],
+}
+
+impl Blueprint {
+    fn from(input: &str, line: &str) -> Result<Self, ParseError> {
+        let err = |part: &str, msg: &str| {
+            ParseError::at_slice(input.as_bytes(), part.as_bytes(), msg.to_owned())
+        };
+
+        let mut numbers = Vec::with_capacity(7);
+        let mut words = line.split(' ');
+        for expected in TEMPLATE.split_whitespace() {
+            let word = words
+                .next()
+                .ok_or_else(|| err(&line[line.len()..], &format!("expected `{expected}`")))?;
+            match expected.strip_prefix('#') {
+                Some(rest) => {
+                    let number = word
+                        .strip_suffix(rest)
+                        .and_then(|n| n.parse::<u8>().ok())
+                        .ok_or_else(|| err(word, "expected a number"))?;
+                    numbers.push(Count::from(number));
+                }
+                None if word != expected => {
+                    return Err(err(word, &format!("expected `{expected}`")));
+                }
+                None => (),
+            }
+        }
+        if let Some(word) = words.next() {
+            return Err(err(word, "expected the end of the blueprint"));
+        }
+
+        let [id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+            numbers[..].try_into().unwrap();
+        let costs = [
+            [ore, 0, 0],
+            [clay, 0, 0],
+            [obsidian_ore, obsidian_clay, 0],
+            [geode_ore, 0, geode_obsidian],
+        ];
+        let mut most = [0; 3];
+        for cost in &costs {
+            for (m, &c) in most.iter_mut().zip(cost) {
+                *m = (*m).max(c);
+            }
+        }
+        Ok(Self {
+            id: id.into(),
+            costs,
+            most,
+        })
+    }
+
+    /// Most geodes that can be opened in `minutes`, starting with one ore
+    /// robot.
+    fn geodes(&self, minutes: Count) -> Count {
+        let mut best = 0;
+        rec(self, [1, 0, 0, 0], [0; 4], minutes, &mut best);
+        debug!("blueprint {}: {best} geodes", self.id);
+        best
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Builds the robots one after the other, each time waiting until the next one
+/// can be paid. Gives up on a branch when even a new geode robot every minute
+/// would not beat `best`.
+fn rec(bp: &Blueprint, robots: [Count; 4], stock: [Count; 4], minutes: Count, best: &mut Count) {
+    let geodes = stock[GEODE] + robots[GEODE] * minutes;
+    *best = (*best).max(geodes);
+    if geodes + minutes * minutes.saturating_sub(1) / 2 <= *best {
+        return;
+    }
+
+    for robot in (ORE..=GEODE).rev() {
+        if robot != GEODE && robots[robot] >= bp.most[robot] {
+            continue;
+        }
+        let costs = &bp.costs[robot];
+        let mut wait = 0;
+        for res in ORE..=OBSIDIAN {
+            let missing = costs[res].saturating_sub(stock[res]);
+            if missing > 0 {
+                if robots[res] == 0 {
+                    wait = minutes;
+                    break;
+                }
+                wait = wait.max(missing.div_ceil(robots[res]));
+            }
+        }
+        // A robot built in the last minute opens no geode.
+        let Some(rem_minutes) = minutes.checked_sub(wait + 1).filter(|&m| m > 0) else {
+            continue;
+        };
+
+        let mut next_stock = stock;
+        for res in ORE..=GEODE {
+            next_stock[res] += robots[res] * (wait + 1);
+        }
+        for res in ORE..=OBSIDIAN {
+            next_stock[res] -= costs[res];
+        }
+        let mut next_robots = robots;
+        next_robots[robot] += 1;
+        rec(bp, next_robots, next_stock, rem_minutes, best);
+    }
+}
+
+/// Robot blueprints, one per line.
+pub fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    str_lines(input)
+        .map(|line| Blueprint::from(input, line))
+        .collect()
+}
+
+/// Sum of the quality levels in 24 minutes.
+pub fn part1(blueprints: &[Blueprint]) -> Score {
+    blueprints
+        .iter()
+        .map(|bp| bp.id * Score::from(bp.geodes(24)))
+        .sum()
+}
+
+/// Product of the geodes of the first three blueprints in 32 minutes.
+pub fn part2(blueprints: &[Blueprint]) -> Score {
+    blueprints
+        .iter()
+        .take(3)
+        .map(|bp| Score::from(bp.geodes(32)))
+        .product()
+}
+
+/// `size` blueprints with costs in the ranges of the puzzle input.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size.clamp(1, 30) {
+        writeln!(
+            out,
+            "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            rng.random_range(2..=4),
+            rng.random_range(2..=4),
+            rng.random_range(2..=4),
+            rng.random_range(5..=20),
+            rng.random_range(2..=4),
+            rng.random_range(5..=20),
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2};
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn test_parse() {
+        let blueprints = parse(INPUT).unwrap();
+        assert_eq!(blueprints.len(), 2);
+        assert_eq!(blueprints[0].id, 1);
+        assert_eq!(
+            blueprints[0].costs,
+            [[4, 0, 0], [2, 0, 0], [3, 14, 0], [2, 0, 7]]
+        );
+        assert_eq!(blueprints[1].most, [3, 8, 12]);
+
+        let e = parse(&INPUT.replace("14 clay", "x clay")).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 107, "x"));
+        let e = parse(&INPUT.replace("2 ore. Each obsidian", "2 ore. Each diamond")).unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (1, "diamond"));
+        assert_eq!(e.msg, "expected `obsidian`");
+        let e = parse("Blueprint 1: Each ore robot").unwrap_err();
+        assert_eq!(e.msg, "expected `costs`");
+        let e = parse(&INPUT.replace("obsidian.\n", "obsidian. Twice.\n")).unwrap_err();
+        assert_eq!(e.text, "Twice.");
+    }
+
+    #[test]
+    fn test_example() {
+        let blueprints = parse(INPUT).unwrap();
+        assert_eq!(blueprints[0].geodes(24), 9);
+        assert_eq!(blueprints[1].geodes(24), 12);
+        assert_eq!(part1(&blueprints), 33);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let blueprints = parse(INPUT).unwrap();
+        assert_eq!(blueprints[0].geodes(32), 56);
+        assert_eq!(blueprints[1].geodes(32), 62);
+        assert_eq!(part2(&blueprints), 56 * 62);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(19);
+        for size in [1, 5] {
+            let blueprints = parse(&generate(&mut rng, size)).unwrap();
+            assert_eq!(blueprints.len(), size);
+            for bp in &blueprints {
+                assert!(bp.geodes(20) <= bp.geodes(24));
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+fn main() -> std::process::ExitCode {
+    aoc_common::run(&day19::Solver)
+}
@@ -0,0 +1,289 @@
+use aoc_common::{lines, Answer, ParseError, Solution};
+use log::trace;
+use rand::{seq::IndexedRandom, RngCore};
+use std::fmt::Write;
+
+/// Opponent move and the raw second column, its meaning differs per part.
+pub struct Round(PRS, u8);
+
+pub fn parse(input: &[u8]) -> Result<Vec<Round>, ParseError> {
+    lines(input)
+        .map(|line| match *line {
+            [opponent @ b'A'..=b'C', b' ', column @ b'X'..=b'Z'] => {
+                Ok(Round(PRS::from(opponent), column))
+            }
+            _ => Err(ParseError::at_slice(input, line, "expected `A|B|C X|Y|Z`")),
+        })
+        .collect()
+}
+
+/// What one round of the guide scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Played {
+    /// 1 for rock, 2 for paper, 3 for scissors.
+    pub shape: u8,
+    /// 0 for a loss, 3 for a draw, 6 for a win.
+    pub outcome: u8,
+}
+
+impl Played {
+    pub fn score(&self) -> u8 {
+        self.shape + self.outcome
+    }
+}
+
+/// Total score of a guide and the score of each of its rounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tally {
+    pub total: u32,
+    pub rounds: Vec<Played>,
+}
+
+impl From<Tally> for Answer {
+    fn from(tally: Tally) -> Self {
+        tally.total.into()
+    }
+}
+
+fn play(guide: &[Round], choose: impl Fn(PRS, u8) -> PRS) -> Tally {
+    let mut total = 0;
+    let mut rounds = Vec::with_capacity(guide.len());
+
+    for (n, &Round(move_opponent, column)) in guide.iter().enumerate() {
+        let move_me = choose(move_opponent, column);
+        let score_won = Score::play(move_opponent, move_me);
+        let played = Played {
+            shape: move_me as u8,
+            outcome: score_won as u8,
+        };
+        trace!(
+            "{}: Opponent: {move_opponent:?} Me: {move_me:?}: {score_won:?} ( {} + {} ) = {}",
+            n + 1,
+            played.shape,
+            played.outcome,
+            played.score()
+        );
+
+        total += u32::from(played.score());
+        rounds.push(played);
+    }
+
+    Tally { total, rounds }
+}
+
+/// The second column is the shape to play.
+pub fn part1(guide: &[Round]) -> Tally {
+    play(guide, |_, column| PRS::strategy_play(column))
+}
+
+/// The second column is how the round has to end.
+pub fn part2(guide: &[Round]) -> Tally {
+    play(guide, |opponent, column| {
+        opponent.known_score(Score::from(column))
+    })
+}
+
+/// `size` rounds of the strategy guide.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let opponent = b"ABC".choose(rng).unwrap();
+        let column = b"XYZ".choose(rng).unwrap();
+        writeln!(out, "{} {}", char::from(*opponent), char::from(*column)).unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone)]
+#[repr(u8)]
+enum PRS {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl std::fmt::Debug for PRS {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rock => write!(f, "Rock    "),
+            Self::Paper => write!(f, "Paper   "),
+            Self::Scissors => write!(f, "Scissors"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum Score {
+    Lost = 0,
+    Draw = 3,
+    Won = 6,
+}
+
+impl Score {
+    fn from(inp: u8) -> Self {
+        match inp {
+            b'A' | b'X' => Self::Lost,
+            b'B' | b'Y' => Self::Draw,
+            b'C' | b'Z' => Self::Won,
+            _ => panic!("inp {inp}"),
+        }
+    }
+}
+
+impl PRS {
+    fn from(inp: u8) -> Self {
+        match inp {
+            b'A' | b'X' => Self::Rock,
+            b'B' | b'Y' => Self::Paper,
+            b'C' | b'Z' => Self::Scissors,
+            _ => panic!("inp {inp}"),
+        }
+    }
+
+    fn strategy_play(column: u8) -> Self {
+        Self::from(column)
+    }
+
+    fn known_score(&self, score: Score) -> Self {
+        match (self, score) {
+            (PRS::Rock, Score::Lost) => Self::Scissors,
+            (PRS::Rock, Score::Draw) => Self::Rock,
+            (PRS::Rock, Score::Won) => Self::Paper,
+            (PRS::Paper, Score::Lost) => Self::Rock,
+            (PRS::Paper, Score::Draw) => Self::Paper,
+            (PRS::Paper, Score::Won) => Self::Scissors,
+            (PRS::Scissors, Score::Lost) => Self::Paper,
+            (PRS::Scissors, Score::Draw) => Self::Scissors,
+            (PRS::Scissors, Score::Won) => Self::Rock,
+        }
+    }
+}
+
+impl Score {
+    fn play(oponent: PRS, myself: PRS) -> Self {
+        match (oponent, myself) {
+            (PRS::Rock, PRS::Rock) => Self::Draw,
+            (PRS::Rock, PRS::Paper) => Self::Won,
+            (PRS::Rock, PRS::Scissors) => Self::Lost,
+            (PRS::Paper, PRS::Rock) => Self::Lost,
+            (PRS::Paper, PRS::Paper) => Self::Draw,
+            (PRS::Paper, PRS::Scissors) => Self::Won,
+            (PRS::Scissors, PRS::Rock) => Self::Won,
+            (PRS::Scissors, PRS::Paper) => Self::Lost,
+            (PRS::Scissors, PRS::Scissors) => Self::Draw,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2, Played, Score, PRS};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        let mut score_total: u32 = 0;
+        let ans_score = [8, 1, 6];
+
+        for (data, ans) in INPUT.chunks(4).zip(ans_score) {
+            let move_opponent = PRS::from(data[0]);
+            let move_me = PRS::strategy_play(data[2]);
+            let mut score_round = move_me as u8;
+            let score_won = Score::play(move_opponent, move_me);
+            score_round += score_won as u8;
+            assert_eq!(score_round, ans);
+            score_total += u32::from(score_round);
+        }
+        assert_eq!(score_total, 15);
+
+        let tally = part1(&parse(INPUT).unwrap());
+        assert_eq!(tally.total, 15);
+        let scores: Vec<_> = tally.rounds.iter().map(Played::score).collect();
+        assert_eq!(scores, ans_score);
+        assert_eq!(
+            tally.rounds[1],
+            Played {
+                shape: 1,
+                outcome: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let mut score_total: u32 = 0;
+        let ans_score = [Score::Draw, Score::Lost, Score::Won];
+
+        for (data, ans) in INPUT.chunks(4).zip(ans_score) {
+            let move_opponent = PRS::from(data[0]);
+            let outcome = Score::from(data[2]);
+            let move_me = move_opponent.known_score(outcome);
+            let mut score_round = move_me as u8;
+            let score_won = Score::play(move_opponent, move_me);
+            score_round += score_won as u8;
+            assert_eq!(score_won, ans);
+            score_total += u32::from(score_round);
+        }
+        assert_eq!(score_total, 12);
+
+        let tally = part2(&parse(INPUT).unwrap());
+        assert_eq!(tally.total, 12);
+        let outcomes: Vec<_> = tally.rounds.iter().map(|p| p.outcome).collect();
+        assert_eq!(outcomes, ans_score.map(|s| s as u8));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(b"A Y\nB W\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "B W"));
+        let e = parse(b"A Y\nBB Y\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "BB Y"));
+
+        assert_eq!(
+            part2(&parse(b"A Y\r\nB X\r\nC Z\r\n\r\n").unwrap()).total,
+            12
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(2);
+        for size in [1, 100] {
+            let guide = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(guide.len(), size);
+            for tally in [part1(&guide), part2(&guide)] {
+                assert_eq!(tally.rounds.len(), size);
+                assert!(tally.total <= 9 * size as u32);
+            }
+        }
+    }
+}
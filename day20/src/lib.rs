@@ -0,0 +1,282 @@
+use aoc_common::{as_str, str_lines, Answer, ParseError, Solution};
+use log::debug;
+use rand::{Rng, RngCore};
+use std::fmt::Write;
+
+/// Encrypted file, one number per line.
+pub fn parse(input: &str) -> Result<Grove, ParseError> {
+    Grove::new(input)
+}
+
+pub fn part1(dec: &Grove) -> Gps {
+    let ret = dec.process(1);
+
+    let total = ret.1.iter().sum();
+
+    debug!("{:?}: {total}", ret.1);
+
+    total
+}
+
+pub fn part2(dec: &Grove) -> Gps {
+    let mut dec = dec.clone();
+    dec.set_key(811589153);
+
+    let ret = dec.process(10);
+
+    let total = ret.1.iter().sum();
+
+    debug!("{:?}: {total}", ret.1);
+
+    total
+}
+
+/// `size` numbers, one of them the single 0.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let count = size.clamp(2, usize::from(Idx::MAX));
+    let zero = rng.random_range(0..count);
+    let mut out = String::new();
+    for n in 0..count {
+        let v = if n == zero {
+            0
+        } else {
+            loop {
+                let v: Gps = rng.random_range(-10_000..=10_000);
+                if v != 0 {
+                    break v;
+                }
+            }
+        };
+        writeln!(out, "{v}").unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+pub type Gps = i64;
+type Idx = u16;
+
+#[derive(Debug, Clone)]
+pub struct Grove(Vec<Gps>);
+
+impl Grove {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let err = |part: &str, msg| ParseError::at_slice(input.as_bytes(), part.as_bytes(), msg);
+
+        let list = str_lines(input)
+            .map(|v| v.parse::<Gps>().map_err(|_| err(v, "expected a number")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let end = &input[input.len()..];
+        if list.len() < 2 || list.len() > usize::from(Idx::MAX) {
+            return Err(err(end, "expected 2 to 65535 numbers"));
+        }
+        if !list.contains(&0) {
+            return Err(err(end, "no 0 in the file"));
+        }
+        Ok(Self(list))
+    }
+
+    fn set_key(&mut self, key: Gps) {
+        for v in self.0.iter_mut() {
+            *v *= key;
+        }
+    }
+
+    fn insert_pos(&self, idx: Idx, value: Gps) -> usize {
+        let val = value as isize;
+        let idx = idx as isize;
+
+        let len = self.0.len() as isize;
+
+        let mut ipos = idx + val;
+
+        ipos = ipos.rem_euclid(len - 1);
+
+        if val != 0 && idx + val == 0 {
+            ipos = len - 1;
+        }
+
+        // println!("{val} {idx} {ipos}");
+ 
+        ipos as usize
+    }
+
+    fn process(&self, times: u8) -> (Vec<Idx>, Vec<Gps>) {
+        let idx_len = Idx::try_from(self.0.len()).unwrap();
+        let mut list: Vec<Idx> = (0..idx_len).collect();
+
+        for _ in 0..times {
+            for (idx, &val) in self.0.iter().enumerate() {
+                let list_idx = list.iter().position(|v| *v == idx as Idx ).unwrap() as Idx;
+
+                let ins_idx = self.insert_pos(list_idx, val);
+
+                let item = list.remove(list_idx as usize);
+
+                list.insert(ins_idx, item);
+            }
+        }
+
+        let idx_zero = self.0.iter().position(|v| *v == 0 ).unwrap();
+        let idx_zero = list.iter().position(|v| *v == idx_zero as u16 ).unwrap();
+
+        let mut cor = Vec::<Gps>::with_capacity(3);
+        for n in 1..=3 {
+            let idx = (idx_zero + (n * 1000)) % self.0.len();
+            let idx = list[idx] as usize;
+            cor.push( self.0[idx] );
+        }
+
+        (list, cor)
+    }
+    
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, Grove, Gps};
+    use proptest::prelude::*;
+    use std::io::Read;
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn test_example1() {
+        let dec = Grove::new(INPUT).unwrap();
+
+        let ret = dec.process(1);
+
+        let num: Vec<Gps> = ret.0.iter().map(|n| dec.0[*n as usize]).collect();
+
+        assert_eq!(num, vec![1, 2, -3, 4, 0, 3, -2]);
+        assert_eq!(ret.1, vec![4, -3, 2])
+    }
+
+    #[test]
+    fn test_example() {
+        let dec = parse(INPUT).unwrap();
+
+        assert_eq!(part1(&dec), 3);
+        assert_eq!(part2(&dec), 1623178306);
+
+        let e = parse("1\n2\n-x\n0").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "-x"));
+        assert_eq!(parse("1\n2").unwrap_err().msg, "no 0 in the file");
+    }
+
+    #[test]
+    fn test_crlf() {
+        let dec = parse(&(INPUT.replace('\n', "\r\n") + "\r\n\r\n")).unwrap();
+        assert_eq!(part1(&dec), 3);
+    }
+
+    #[test]
+    fn test_example2() {
+        let mut dec = Grove::new(INPUT).unwrap();
+
+        dec.set_key(811589153);
+
+        assert_eq!(dec.0, vec![811589153, 1623178306, -2434767459, 2434767459, -1623178306, 0, 3246356612]);
+
+        let ret = dec.process(10);
+
+        let num: Vec<Gps> = ret.0.iter().map(|n| dec.0[*n as usize]).collect();
+
+        assert_eq!(num, vec![0, -2434767459, 1623178306, 3246356612, -1623178306, 2434767459, 811589153]);
+        assert_eq!(ret.1, vec![811589153, 2434767459, -1623178306])
+    }
+
+    #[test]
+    #[ignore = "needs input/input_test.txt"]
+    fn test_example_go() {
+        let mut f = std::fs::File::open("input/input_test.txt").unwrap();
+        let mut input = String::with_capacity(1_000_000);
+        f.read_to_string(&mut input).unwrap();
+
+        let dec = Grove::new(&input).unwrap();
+
+        let ret = dec.process(1);
+
+        let answer: isize = ret.1.iter().map(|v| *v as isize).sum();
+
+        println!("Part1: sum: {}", answer);
+        assert_eq!(answer, 3466);
+    }
+
+    #[test]
+    #[ignore = "needs input/input.txt"]
+    fn test_example_part1() {
+        let mut f = std::fs::File::open("input/input.txt").unwrap();
+        let mut input = String::with_capacity(1_000_000);
+        f.read_to_string(&mut input).unwrap();
+
+        let dec = Grove::new(&input).unwrap();
+
+        let ret = dec.process(1);
+
+        let answer: isize = ret.1.iter().map(|v| *v as isize).sum();
+
+        println!("Part1: sum: {}", answer);
+        assert_eq!(answer, 4914);
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(20);
+        for size in [1, 7, 300] {
+            let grove = parse(&generate(&mut rng, size)).unwrap();
+            assert_eq!(grove.0.len(), size.max(2));
+            assert_eq!(grove.0.iter().filter(|&&v| v == 0).count(), 1);
+            part1(&grove);
+            part2(&grove);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_mix_keeps_values(
+            mut values in prop::collection::vec(-10_000..=10_000 as Gps, 1..100),
+            zero in any::<prop::sample::Index>(),
+            times in 1..=3_u8,
+        ) {
+            let at = zero.index(values.len() + 1);
+            values.insert(at, 0);
+            let grove = Grove(values.clone());
+
+            let (list, _) = grove.process(times);
+            let mut mixed: Vec<Gps> = list.iter().map(|&i| values[usize::from(i)]).collect();
+            mixed.sort_unstable();
+            values.sort_unstable();
+            prop_assert_eq!(mixed, values);
+        }
+    }
+}
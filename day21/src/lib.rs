@@ -0,0 +1,504 @@
+use aoc_common::{as_str, str_lines, Answer, ParseError, Solution};
+use nom::{
+    self,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, one_of},
+    combinator::all_consuming,
+    sequence::{delimited, tuple},
+    Finish, IResult, Parser,
+};
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+pub enum Ans<'a> {
+    Var(&'a str),
+    Num(i64),
+}
+
+#[derive(Debug, Clone)]
+pub enum Input<'a> {
+    Eq((&'a str, Ans<'a>, char, Ans<'a>)),
+    Var((&'a str, i64)),
+}
+
+fn line(input: &str) -> IResult<&str, Input<'_>> {
+    let (input, var) = alpha1(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, out) = alt((
+        complete::i64.map(|n| Input::Var((var, n))),
+        tuple((
+            alpha1,
+            delimited(tag(" "), one_of("*+-/"), tag(" ")),
+            alpha1,
+        ))
+        .map(|(left, op, right)| Input::Eq((var, Ans::Var(left), op, Ans::Var(right)))),
+    ))(input)?;
+    //println!("Line: {out:?}");
+    Ok((input, out))
+}
+
+/// One monkey job per line.
+pub fn parse(input: &str) -> Result<Vec<Input<'_>>, ParseError> {
+    let err = |at: &str| {
+        let msg = "expected `name: number` or `name: a op b`";
+        ParseError::at_slice(input.as_bytes(), at.as_bytes(), msg)
+    };
+
+    str_lines(input)
+        .map(|l| {
+            all_consuming(line)(l)
+                .finish()
+                .map(|(_, job)| job)
+                .map_err(|e| err(e.input))
+        })
+        .collect()
+}
+
+/// Applies `op`, `None` on overflow or division by zero.
+fn calc(l: i64, op: char, r: i64) -> Option<i64> {
+    match op {
+        '*' => l.checked_mul(r),
+        '+' => l.checked_add(r),
+        '/' => l.checked_div(r),
+        '-' => l.checked_sub(r),
+        _ => panic!("No op!"),
+    }
+}
+
+/// What `root` yells. `None` when a monkey is missing, or on overflow or
+/// division by zero.
+pub fn part1<'a>(list: &[Input<'a>]) -> Option<i64> {
+    let mut list = list.to_vec();
+
+    // let total = list.len();
+
+    let mut numbers: HashMap<&str, i64> = list
+        .iter()
+        .filter_map(|e| {
+            if let Input::Var((var, n)) = e {
+                Some((*var, *n))
+            } else {
+                None
+            }
+        })
+        .collect();
+    list.retain(|e| matches!(e, Input::Eq(_)));
+
+    let mut index = 0;
+    let mut progress = true;
+    let ans: i64 = loop {
+        if list.is_empty() {
+            break 0;
+        }
+        let (var, left, op, right) = if let Some(Input::Eq(n)) = list.get_mut(index) {
+            n
+        } else {
+            // println!("Can´t get {index}");
+            // A whole pass without news, a monkey is missing.
+            if !progress {
+                return None;
+            }
+            progress = false;
+            index = 0;
+            continue;
+        };
+
+        if let Ans::Var(v) = left {
+            if let Some(n) = numbers.get(v) {
+                *left = Ans::Num(*n);
+            }
+        }
+
+        if let Ans::Var(v) = right {
+            if let Some(n) = numbers.get(v) {
+                *right = Ans::Num(*n);
+            }
+        }
+
+        if let (Ans::Num(l), Ans::Num(r)) = (left, right) {
+            let ans = calc(*l, *op, *r)?;
+            if *var == "root" {
+                break ans;
+            }
+            // println!("status: [{index}] {var} = {ans} -- {}/{total}", numbers.len() + 1);
+
+            numbers.insert(var, ans);
+            list.swap_remove(index);
+            progress = true;
+        }
+
+        index += 1;
+    };
+
+    Some(ans)
+}
+
+/// What `humn` has to yell for the two sides of `root` to match. `None` like
+/// [`part1`], or when `humn` is not on exactly one side of `root`.
+pub fn part2<'a>(list: &[Input<'a>]) -> Option<i64> {
+    let mut list = list.to_vec();
+
+    let _total = list.len();
+
+    let mut numbers: HashMap<&str, i64> = list
+        .iter()
+        .filter_map(|e| {
+            if let Input::Var((var, n)) = e {
+                Some((*var, *n))
+            } else {
+                None
+            }
+        })
+        .collect();
+    list.retain(|e| matches!(e, Input::Eq(_)));
+
+    numbers.remove("humn");
+
+    let mut index = 0;
+    let mut progress = true;
+    let ans: (Ans, Ans) = loop {
+        let (var, left, op, right) = if let Some(Input::Eq(n)) = list.get_mut(index) {
+            n
+        } else {
+            // println!("Can´t get {index}");
+            // A whole pass without news, a monkey is missing.
+            if !progress {
+                return None;
+            }
+            progress = false;
+            index = 0;
+            continue;
+        };
+
+        if let Ans::Var(v) = left {
+            if let Some(n) = numbers.get(v) {
+                *left = Ans::Num(*n);
+                if *var == "root" {
+                    break (left.clone(), right.clone());
+                }
+            }
+        }
+
+        if let Ans::Var(v) = right {
+            if let Some(n) = numbers.get(v) {
+                *right = Ans::Num(*n);
+                if *var == "root" {
+                    break (left.clone(), right.clone());
+                }
+            }
+        }
+
+        if let (Ans::Num(l), Ans::Num(r)) = (left, right) {
+            let ans = calc(*l, *op, *r)?;
+
+            // println!("status: [{index}] {var} = {ans} -- {}/{total}", numbers.len() + 1);
+
+            numbers.insert(var, ans);
+            list.swap_remove(index);
+            progress = true;
+        }
+
+        index += 1;
+    };
+
+    // println!("Ans: {ans:?}");
+
+    let (name, num) = match ans {
+        (Ans::Var(name), Ans::Num(num)) | (Ans::Num(num), Ans::Var(name)) => (name, num),
+        _ => return None,
+    };
+    if name == "humn" {
+        return Some(num);
+    }
+
+    numbers.insert(name, num);
+    list.swap_remove(index);
+
+    let mut index = 0;
+    let mut progress = true;
+    let ans: i64 = loop {
+        let (var, left, op, right) = if let Some(Input::Eq(n)) = list.get_mut(index) {
+            n
+        } else {
+            // println!("Can´t get {index}");
+            // A whole pass without news, a monkey is missing.
+            if !progress {
+                return None;
+            }
+            progress = false;
+            index = 0;
+            continue;
+        };
+
+        if let Ans::Var(v) = left {
+            if let Some(n) = numbers.get(v) {
+                *left = Ans::Num(*n);
+            }
+        }
+
+        if let Ans::Var(v) = right {
+            if let Some(n) = numbers.get(v) {
+                *right = Ans::Num(*n);
+            }
+        }
+
+        if let Some(n_var) = numbers.get(var) {
+            let (op_var, pos, num) = match (left.clone(), right.clone()) {
+                (Ans::Var(_v1), Ans::Var(_v2)) => {
+                    index += 1;
+                    continue;
+                }
+                (Ans::Var(v), Ans::Num(n)) => (v, true, n),
+                (Ans::Num(n), Ans::Var(v)) => (v, false, n),
+                (Ans::Num(_), Ans::Num(_)) => {
+                    // Fully known, nothing left to solve for.
+                    list.swap_remove(index);
+                    progress = true;
+                    index = 0;
+                    continue;
+                }
+            };
+
+            // println!("{n_var} {var} = {op_var}, {pos}, {num} ");
+
+            let ans: i64 = if !pos {
+                // V = NUM * R =>
+                //
+                match op {
+                    '*' => calc(*n_var, '/', num)?,
+                    '+' => calc(*n_var, '-', num)?,
+                    '/' => calc(num, '/', *n_var)?,
+                    '-' => calc(num, '-', *n_var)?,
+                    _ => panic!("No op!"),
+                }
+            } else {
+                match op {
+                    '*' => calc(*n_var, '/', num)?,
+                    '+' => calc(*n_var, '-', num)?,
+                    '/' => calc(*n_var, '*', num)?,
+                    '-' => calc(*n_var, '+', num)?,
+                    _ => panic!("No op!"),
+                }
+            };
+
+            // println!("rev: [{index}] {var} {op_var} = {ans} -- {}/{total}", numbers.len() + 1);
+            if op_var == "humn" {
+                break ans;
+            };
+            numbers.insert(op_var, ans);
+            list.swap_remove(index);
+            progress = true;
+            index = 0;
+            continue;
+        }
+
+        if let (Ans::Num(l), Ans::Num(r)) = (left, right) {
+            let ans = calc(*l, *op, *r)?;
+
+            // println!("status: [{index}] {var} = {ans} -- {}/{total}", numbers.len() + 1);
+
+            numbers.insert(var, ans);
+            list.swap_remove(index);
+            progress = true;
+        }
+
+        index += 1;
+    };
+
+    // println!("humn: {ans}");
+
+    Some(ans)
+}
+
+/// Monkey jobs for [`generate`], with unique names.
+struct Jobs {
+    lines: Vec<String>,
+    names: HashSet<String>,
+}
+
+impl Jobs {
+    fn name(&mut self, rng: &mut dyn RngCore) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| char::from(rng.random_range(b'a'..=b'z')))
+                .collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn number(&mut self, rng: &mut dyn RngCore, value: i64) -> String {
+        let name = self.name(rng);
+        self.lines.push(format!("{name}: {value}"));
+        name
+    }
+
+    fn op(&mut self, rng: &mut dyn RngCore, left: &str, op: char, right: &str) -> String {
+        let name = self.name(rng);
+        self.lines.push(format!("{name}: {left} {op} {right}"));
+        name
+    }
+
+    /// Expression of `leaves` numbers, with its name and value.
+    fn tree(&mut self, rng: &mut dyn RngCore, leaves: usize) -> (String, i64) {
+        if leaves <= 1 {
+            let value = rng.random_range(1..=20);
+            return (self.number(rng, value), value);
+        }
+        let split = rng.random_range(1..leaves);
+        let (left, l) = self.tree(rng, split);
+        let (right, r) = self.tree(rng, leaves - split);
+
+        let mut ops = vec![('+', l + r), ('-', l - r)];
+        if let Some(v) = l.checked_mul(r).filter(|v| v.abs() < 1_000_000_000_000) {
+            ops.push(('*', v));
+        }
+        if r != 0 && l % r == 0 {
+            ops.push(('/', l / r));
+        }
+        let (op, value) = ops[rng.random_range(0..ops.len())];
+        (self.op(rng, &left, op, &right), value)
+    }
+}
+
+/// Jobs of about `size` monkeys. `humn` is on the left of `root` and every
+/// division on its way up is exact, so part 2 has one answer: the number
+/// `humn` yells.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut jobs = Jobs {
+        lines: Vec::new(),
+        names: HashSet::new(),
+    };
+
+    let humn: i64 = rng.random_range(1..=1000);
+    jobs.lines.push(format!("humn: {humn}"));
+    let (mut chain, mut value) = ("humn".to_owned(), humn);
+    for _ in 0..(size / 3).max(1) {
+        let k = rng.random_range(2..=5);
+        let left = rng.random_bool(0.5);
+        let (op, next) = match rng.random_range(0..4) {
+            0 if value.abs() < 1_000_000_000 => ('*', value * k),
+            1 if value % k == 0 => ('/', value / k),
+            2 => ('-', if left { value - k } else { k - value }),
+            _ => ('+', value + k),
+        };
+        let k = jobs.number(rng, k);
+        chain = if left || op == '/' {
+            jobs.op(rng, &chain, op, &k)
+        } else {
+            jobs.op(rng, &k, op, &chain)
+        };
+        value = next;
+    }
+
+    let (right, target) = jobs.tree(rng, size / 3 + 1);
+    let (op, diff) = if target >= value {
+        ('+', target - value)
+    } else {
+        ('-', value - target)
+    };
+    let diff = jobs.number(rng, diff);
+    let left = jobs.op(rng, &chain, op, &diff);
+    jobs.lines.push(format!("root: {left} + {right}"));
+
+    jobs.lines.shuffle(rng);
+    let mut out = jobs.lines.join("\n");
+    out.push('\n');
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn example_part1() {
+        let ans = part1(&parse(INPUT).unwrap());
+        assert_eq!(ans, Some(152));
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()), Some(301));
+    }
+
+    #[test]
+    fn unsolvable() {
+        let solve = |input| {
+            let jobs = parse(input).unwrap();
+            (part1(&jobs), part2(&jobs))
+        };
+        assert_eq!(
+            solve("root: aaaa + humn\naaaa: 4\nhumn: 5\n"),
+            (Some(9), Some(4))
+        );
+        assert_eq!(
+            solve("root: aaaa / bbbb\naaaa: 4\nbbbb: 0\nhumn: 5\n"),
+            (None, None)
+        );
+        assert_eq!(solve("root: aaaa + bbbb\naaaa: 4\nhumn: 5\n"), (None, None));
+        assert_eq!(solve("root: aaaa + aaaa\naaaa: 4\n"), (Some(8), None));
+        let max = format!("root: aaaa * aaaa\naaaa: {}\nhumn: 1\n", i64::MAX);
+        assert_eq!(solve(&max), (None, None));
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(parse("root: pppw + sjmn").unwrap().len(), 1);
+        assert_eq!(parse("dbpl: 5\r\nroot: pppw + sjmn\r\n").unwrap().len(), 2);
+
+        let e = parse("dbpl: 5\ncczh: sllz % lgvd\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 12, "% lgvd"));
+        let e = parse("dbpl: 5x\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 8, "x"));
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(21);
+        for size in [1, 10, 100, 2000] {
+            let input = generate(&mut rng, size);
+            let jobs = parse(&input).unwrap();
+            let humn = jobs
+                .iter()
+                .find_map(|job| match job {
+                    Input::Var(("humn", n)) => Some(*n),
+                    _ => None,
+                })
+                .unwrap();
+            assert_eq!(part2(&jobs), Some(humn));
+            assert_eq!(part1(&jobs).unwrap() % 2, 0);
+        }
+    }
+}
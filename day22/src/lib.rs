@@ -0,0 +1,271 @@
+use aoc_common::{lines, Answer, Grid, ParseError, Solution};
+
+use nom::branch::alt;
+use nom::character::complete::{self, one_of};
+use nom::combinator::iterator;
+use nom::error::ErrorKind;
+use nom::Parser;
+use rand::{seq::IndexedRandom, Rng, RngCore};
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy)]
+enum Direction {
+    #[default]
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+impl Direction {
+    fn right(&mut self) {
+        *self = match self {
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+            Self::Up => Self::Right,
+        }
+    }
+    fn left(&mut self) {
+        *self = match self {
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+            Self::Up => Self::Left,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Steps {
+    Right,
+    Left,
+    Num(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Jungle {
+    field: Grid<u8>,
+    current: Loc,
+    dir: Direction,
+    guidens: Vec<Steps>,
+}
+
+type Loc = aoc_common::Loc<u8>;
+
+impl Jungle {
+    fn from(input: &[u8]) -> Result<Self, ParseError> {
+        let mut lines = lines(input);
+
+        let rows: Vec<&[u8]> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+        for row in &rows {
+            if let Some(p) = row.iter().position(|v| !b" .#".contains(v)) {
+                return Err(ParseError::at_slice(
+                    input,
+                    &row[p..=p],
+                    "expected ` `, `.` or `#`",
+                ));
+            }
+        }
+
+        if let Some(row) = rows.iter().find(|row| row.len() > 255).or(rows.get(255)) {
+            return Err(ParseError::at_slice(input, row, "board is too large"));
+        }
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut field = Grid::new(width, rows.len(), b' ');
+        for (y, row) in rows.iter().enumerate() {
+            field.row_mut(y)[..row.len()].copy_from_slice(row);
+        }
+
+        let idx = rows
+            .first()
+            .and_then(|row| row.iter().position(|v| *v == b'.'))
+            .ok_or_else(|| ParseError::at(input, 0, "no open tile on the top row"))?;
+
+        let path = lines.next().unwrap_or_default();
+
+        let mut it = iterator(
+            path,
+            alt((
+                one_of::<_, _, (&[u8], ErrorKind)>("RL").map(|v| match v {
+                    'L' => Steps::Left,
+                    _ => Steps::Right,
+                }),
+                complete::u8.map(Steps::Num),
+            )),
+        );
+        let guidens = it.collect::<Vec<Steps>>();
+        let (rest, ()) = it
+            .finish()
+            .map_err(|_| ParseError::at_slice(input, path, "expected a path"))?;
+        if !rest.is_empty() {
+            return Err(ParseError::at_slice(
+                input,
+                rest,
+                "expected a step count, `R` or `L`",
+            ));
+        }
+
+        Ok(Self {
+            field,
+            current: Loc { x: idx as u8, y: 0 },
+            dir: Direction::default(),
+            guidens,
+        })
+    }
+
+    fn find(&self) -> Option<Loc> {
+        let (dx, dy) = match self.dir {
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+        };
+        let mut pos = self.current;
+
+        loop {
+            let next = self
+                .field
+                .wrap(isize::from(pos.x) + dx, isize::from(pos.y) + dy);
+            // Fits, the board is at most 255 by 255.
+            pos = Loc {
+                x: next.x as u8,
+                y: next.y as u8,
+            };
+
+            match self.field[next] {
+                b' ' => continue,
+                b'.' => return Some(pos),
+                b'#' => return None,
+                e => panic!("Unkown {e} loc {pos}"),
+            }
+        }
+    }
+
+    pub fn walk(&mut self) -> usize {
+        'lus: for step in &self.guidens {
+            // println!("Step: {step:?}");
+            match step {
+                Steps::Right => self.dir.right(),
+                Steps::Left => self.dir.left(),
+                Steps::Num(num) => {
+                    for _n in 0..*num {
+                        let pos = self.find();
+                        if let Some(loc) = pos {
+                            // println!("{n}: {:?} -> {loc:?}", self.current);
+                            self.current = loc;
+                        } else {
+                            continue 'lus;
+                        }
+                    }
+                }
+            }
+        }
+
+        (self.current.y as usize + 1) * 1000 + (self.current.x as usize + 1) * 4 + self.dir as usize
+    }
+}
+
+/// The board with the path description below it.
+pub fn parse(input: &[u8]) -> Result<Jungle, ParseError> {
+    Jungle::from(input)
+}
+
+pub fn part1(jungle: &Jungle) -> usize {
+    jungle.clone().walk()
+}
+
+/// Board folded from a cube net with faces of `size` / 4 tiles, and a path of
+/// `size` turns.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    const NETS: [&[&[u8]]; 2] = [&[b"..#", b"###", b"..##"], &[b".##", b".#", b"##", b"#"]];
+
+    let side = (size / 4).clamp(2, 50);
+    let net = NETS.choose(rng).unwrap();
+    let mut out = String::new();
+    for (face_y, faces) in net.iter().enumerate() {
+        for y in 0..side {
+            for &face in *faces {
+                for x in 0..side {
+                    out.push(if face == b'.' {
+                        ' '
+                    } else if (face_y, y, x) != (0, 0, 0) && rng.random_bool(0.1) {
+                        '#'
+                    } else {
+                        '.'
+                    });
+                }
+            }
+            out.push('\n');
+        }
+    }
+
+    out.push('\n');
+    write!(out, "{}", rng.random_range(1..=50)).unwrap();
+    for _ in 0..size.max(1) {
+        let turn = if rng.random_bool(0.5) { 'R' } else { 'L' };
+        write!(out, "{turn}{}", rng.random_range(1..=50)).unwrap();
+    }
+    out.push('\n');
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, _input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    use super::*;
+
+    #[test]
+    fn test_example1() {
+        let jungle = parse(INPUT).unwrap();
+
+        println!("jungle {:?}", jungle.current);
+        println!("jungle {:?}", jungle.guidens);
+
+        assert_eq!(part1(&jungle), 6032);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(b"  ..#\n  .x.\n\n10R5").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, "x"));
+        let e = parse(b"  ..#\n  ...\n\n10R5X2").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 5, "X2"));
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(22);
+        for size in [1, 16, 200] {
+            let jungle = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(jungle.guidens.len(), 2 * size.max(1) + 1);
+            assert!(part1(&jungle) > 1000);
+        }
+    }
+}
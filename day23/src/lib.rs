@@ -0,0 +1,432 @@
+use aoc_common::{lines, Answer, Frame, Image, Palette, ParseError, Part, Snapshot, Solution};
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Elf positions, `#` marks an elf.
+pub fn parse(input: &[u8]) -> Result<Grove, ParseError> {
+    Grove::new(input)
+}
+
+/// Empty ground in the bounding rectangle after 10 rounds.
+pub fn part1(grove: &Grove) -> usize {
+    grove.clone().process(Some(10), &mut |_, _| ()).0
+}
+
+pub fn part2(grove: &Grove) -> usize {
+    grove.clone().process(None, &mut |_, _| ()).1
+}
+
+/// Grove of `size` by `size` tiles, about half of them with an elf and at
+/// least two elves.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.max(2);
+    loop {
+        let mut out = String::with_capacity((size + 1) * size);
+        for _ in 0..size {
+            out.extend((0..size).map(|_| if rng.random_bool(0.5) { '#' } else { '.' }));
+            out.push('\n');
+        }
+        if out.matches('#').count() >= 2 {
+            return out;
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn animate(
+        &self,
+        part: Part,
+        input: &[u8],
+        draw: &mut dyn FnMut(&Frame),
+    ) -> Result<Answer, ParseError> {
+        let mut grove = parse(input)?;
+        let mut show = |grove: &Grove, round| {
+            draw(grove.frame().caption(format!("round {round}")));
+        };
+        Ok(match part {
+            Part::One => grove.process(Some(10), &mut show).0,
+            Part::Two => grove.process(None, &mut show).1,
+        }
+        .into())
+    }
+
+    /// Where the elves end up.
+    fn image(&self, input: &[u8]) -> Result<Option<Image>, ParseError> {
+        let mut grove = parse(input)?;
+        grove.process(None, &mut |_, _| ());
+        let size = aoc_common::Loc::new(usize::MAX, usize::MAX);
+        let image = Image::from_frame(&grove.frame(), &Palette::frame(), size);
+        Ok(Some(image))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    /// Steps are rounds, a grove that settled earlier stops there.
+    fn snapshot(
+        &self,
+        part: Part,
+        input: &[u8],
+        steps: usize,
+    ) -> Result<Option<String>, ParseError> {
+        let mut grove = parse(input)?;
+        let steps = match part {
+            Part::One => steps.min(10),
+            Part::Two => steps,
+        };
+        grove.process(Some(steps), &mut |_, _| ());
+        let snapshot = Snapshot {
+            day: 23,
+            part,
+            step: grove.round,
+            state: grove,
+        };
+        Ok(Some(snapshot.to_json()))
+    }
+
+    fn resume(&self, snapshot: &str) -> Result<Option<Answer>, String> {
+        let Snapshot {
+            part,
+            state: mut grove,
+            ..
+        } = Snapshot::<Grove>::from_json(snapshot, 23)?;
+        grove.check()?;
+        Ok(Some(
+            match part {
+                Part::One => grove.process(Some(10), &mut |_, _| ()).0,
+                Part::Two => grove.process(None, &mut |_, _| ()).1,
+            }
+            .into(),
+        ))
+    }
+}
+
+type Idx = i16;
+
+type Loc = aoc_common::Loc<Idx>;
+
+type Moves = HashMap<Loc, Option<Loc>>;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Grove {
+    elves: HashSet<Loc>,
+    #[serde(skip)]
+    moves: Moves,
+    /// Rounds in which elves moved so far.
+    round: usize,
+}
+
+/// A round in which elves moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    /// Counted from 1.
+    pub round: usize,
+    pub moved: usize,
+}
+
+/// See [`Grove::rounds`].
+pub struct Rounds<'a> {
+    grove: &'a mut Grove,
+}
+
+impl Iterator for Rounds<'_> {
+    type Item = Round;
+
+    fn next(&mut self) -> Option<Round> {
+        let moved = self.grove.step()?;
+        Some(Round {
+            round: self.grove.round,
+            moved,
+        })
+    }
+}
+
+const CHECKS: [(u8, Loc); 4] = [ ( 0b1110_0000, Loc::new(0, -1)), (0b0000_0111, Loc::new(0, 1)), ( 0b1001_0100, Loc::new(-1, 0)), (0b0010_1001, Loc::new(1, 0)) ];
+
+fn push_move(moves: &mut Moves, new_pos: Loc, cur_pos: Loc) {
+    let val = if moves.contains_key(&new_pos) {
+        None
+    } else {
+        Some(cur_pos)
+    };
+    // println!("Insert: {new_pos} from {val:?}");
+    moves.insert(new_pos, val);
+}
+
+impl Grove {
+    fn new(input: &[u8]) -> Result<Self, ParseError> {
+        // Leave room for the elves to spread out.
+        const MAX: usize = 10_000;
+
+        let mut elves = HashSet::with_capacity(5000);
+
+        for (line, y) in lines(input).zip(0..) {
+            if line.len() > MAX || y == MAX as Idx {
+                return Err(ParseError::at_slice(input, line, "grove is too large"));
+            }
+            for (c, x) in line.iter().zip(0 as Idx..) {
+                match c {
+                    b'#' => {
+                        elves.insert(Loc::new(x, y));
+                    }
+                    b'.' => (),
+                    _ => {
+                        let x = x as usize;
+                        let msg = "expected `.` or `#`";
+                        return Err(ParseError::at_slice(input, &line[x..=x], msg));
+                    }
+                }
+            }
+        }
+
+        if elves.is_empty() {
+            return Err(ParseError::at(input, input.len(), "expected an elf"));
+        }
+
+        Ok(Self {
+            elves,
+            moves: HashMap::with_capacity(5000),
+            round: 0,
+        })
+    }
+
+    /// Empty ground in the rectangle around the elves and the rounds taken,
+    /// `show` sees the grove after every round. Goes on from the rounds that
+    /// were played before, up to round `cycles` or the first one in which no
+    /// elf moves.
+    fn process(
+        &mut self,
+        cycles: Option<usize>,
+        show: &mut dyn FnMut(&Self, usize),
+    ) -> (usize, usize) {
+        let r = loop {
+            if cycles.is_some_and(|c| self.round >= c) {
+                break self.round;
+            }
+            if self.step().is_none() {
+                break self.round + 1;
+            }
+            show(self, self.round);
+            // dbg!(self.elves.len());
+        };
+
+        let  (x1, x2, y1, y2) = self.get_rectangle();
+
+        ((x2.abs_diff(x1) + 1) as usize * (y2.abs_diff(y1) + 1) as usize - self.elves.len(), r)
+    }
+
+    /// Plays a round, returns how many elves moved or `None` when no elf
+    /// wanted to. That round does not count.
+    fn step(&mut self) -> Option<usize> {
+        let moves = &mut self.moves;
+        // println!("Round: {}", r + 1);
+        moves.clear();
+        for curr_loc in self.elves.iter() {
+            let mut others: u8 = 0;
+            //let mut wall: u8 = 0;
+            for ys in -1..=1 {
+                for xs in -1..=1 {
+                    let search_pos = Loc::new(xs, ys) + *curr_loc;
+                    if search_pos == *curr_loc {
+                        continue;
+                    }
+                    others <<= 1;
+                    // <<= 1;
+                    // let x_valid = curr_loc.0 + xs >= 0 && curr_loc.0 + xs < self.size.x;
+                    // let y_valid = curr_loc.1 + ys >= 0 && curr_loc.1 + ys < self.size.y;
+                    // if x_valid && y_valid {
+                        if self.elves.contains(&search_pos) {
+                            others |= 0x01;
+                        }
+                    // } else {
+                    //     wall |= 0x01;
+                    // }
+                }
+            }
+            // println!("{curr_loc} - {others:08b}");
+
+            if others != 0 {
+                let mut checks_iter = CHECKS.iter().cycle().skip(self.round);
+
+                for _ in 0..CHECKS.len() {
+                    let (mask, pos) = checks_iter.next().unwrap();
+                    if others & mask == 0 {
+                        let new_pos = *curr_loc + *pos;
+                        push_move(moves, new_pos, *curr_loc);
+                        break;
+                    }
+                }
+            }
+        }
+
+        if moves.is_empty() {
+            return None;
+        }
+        for (&new, curr) in moves.iter() {
+            if let Some(pos) = curr {
+                self.elves.remove(pos);
+                self.elves.insert(new);
+            }
+        }
+        self.round += 1;
+
+        Some(self.moves.values().filter(|m| m.is_some()).count())
+    }
+
+    /// The rounds from here on, until the first one in which no elf moves.
+    pub fn rounds(&mut self) -> Rounds<'_> {
+        Rounds { grove: self }
+    }
+
+    /// A hand-edited snapshot could have too few elves to span a rectangle,
+    /// or elves that walk off the [`Idx`] range.
+    fn check(&self) -> Result<(), String> {
+        const MAX: u16 = 20_000;
+
+        if self.elves.len() < 2 {
+            return Err("expected at least two elves".to_owned());
+        }
+        if self
+            .elves
+            .iter()
+            .any(|e| e.x.unsigned_abs() > MAX || e.y.unsigned_abs() > MAX)
+        {
+            return Err("grove is too large".to_owned());
+        }
+        Ok(())
+    }
+
+    fn get_rectangle(&self) -> (Idx, Idx, Idx, Idx) {
+        let xspan = self.elves.iter().map(|p| p.x).minmax();
+        let yspan = self.elves.iter().map(|p| p.y).minmax();
+
+        // `parse` rejects a grove without elves.
+        let (x1, x2) = xspan.into_option().expect("at least one elf");
+        let (y1, y2) = yspan.into_option().expect("at least one elf");
+
+        (x1, x2, y1, y2)
+    }
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new("");
+        for elf in &self.elves {
+            frame.set(elf.x, elf.y, '#');
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_SMALL_START: &[u8] = include_bytes!("../input/example2.txt");
+
+const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_example_small() {
+        let mut dec = Grove::new(INPUT_SMALL_START).unwrap();
+
+        dec.process(Some(3), &mut |grove, round| {
+            println!("round {round}\n{}", grove.frame());
+        });
+
+        let e = parse(b"..#\n.o.").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "o"));
+        let e = parse(b"...").err().unwrap();
+        assert_eq!(e.msg, "expected an elf");
+        assert_eq!(part1(&parse(b"#").unwrap()), 0);
+
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let mut dec = Grove::new(INPUT).unwrap();
+
+        assert_eq!(dec.process(Some(10), &mut |_, _| ()), (110, 10));
+        assert_eq!(part1(&parse(INPUT).unwrap()), 110);
+
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let mut dec = Grove::new(INPUT).unwrap();
+
+        assert_eq!(dec.process(None, &mut |_, _| ()).1, 20);
+        assert_eq!(part2(&parse(INPUT).unwrap()), 20);
+
+    }
+
+    #[test]
+    fn test_rounds() {
+        let mut grove = parse(INPUT_SMALL_START).unwrap();
+        let rounds: Vec<_> = grove.rounds().collect();
+        assert_eq!(rounds.len(), 3);
+        assert_eq!(rounds[0], Round { round: 1, moved: 3 });
+        assert_eq!(grove.rounds().next(), None);
+
+        let mut grove = parse(INPUT).unwrap();
+        assert_eq!(grove.rounds().count(), 19);
+        let mut grove = parse(INPUT).unwrap();
+        grove.rounds().take(4).for_each(drop);
+        assert_eq!(grove.process(Some(10), &mut |_, _| ()), (110, 10));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let json = Solver.snapshot(Part::One, INPUT, 4).unwrap().unwrap();
+        let after = Snapshot::<Grove>::from_json(&json, 23).unwrap();
+        assert_eq!(after.step, 4);
+        assert_eq!(after.state.round, 4);
+        assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(110))));
+
+        let json = Solver.snapshot(Part::Two, INPUT, 100).unwrap().unwrap();
+        assert_eq!(Snapshot::<Grove>::from_json(&json, 23).unwrap().step, 19);
+        assert_eq!(Solver.resume(&json), Ok(Some(Answer::Num(20))));
+
+        let json = Solver.snapshot(Part::Two, b"#.#", 0).unwrap().unwrap();
+        let far = json.replace("\"x\": 2", "\"x\": 30000");
+        assert_eq!(Solver.resume(&far), Err("grove is too large".to_owned()));
+        let one = json.replace("\"x\": 2", "\"x\": 0");
+        assert_eq!(
+            Solver.resume(&one),
+            Err("expected at least two elves".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(23);
+        for size in [1, 5, 20] {
+            let grove = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert!(grove.elves.len() >= 2);
+            part1(&grove);
+            assert!(part2(&grove) >= 1);
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use aoc_common::{bfs, lines, Answer, Grid, ParseError, Solution};
+use log::debug;
+use num::Integer;
+use rand::{seq::IndexedRandom, Rng, RngCore};
+
+pub type Minutes = u16;
+
+/// Position and minute within the blizzard cycle.
+#[derive(Debug, Clone, Copy, Ord, PartialEq, PartialOrd, Eq, Hash)]
+struct Node(Loc, Minutes);
+
+/// The valley with its blizzards.
+pub fn parse(input: &[u8]) -> Result<Sim, ParseError> {
+    Sim::new(input)
+}
+
+/// Minutes to the goal, `None` when the blizzards never let us through.
+pub fn part1(sim: &Sim) -> Option<Minutes> {
+    let valley = sim.clone().sim();
+
+    let end_point = Loc {
+        x: sim.size.x - 2,
+        y: sim.size.y - 1,
+    };
+
+    let start_point = Node(Loc { x: 1, y: 0 }, 0_u16);
+
+    let ans = find_path(&valley, start_point, end_point)?;
+
+    Some(ans.1)
+}
+
+/// Minutes to the goal, back to the start and to the goal again.
+pub fn part2(map_data: &Sim) -> Option<Minutes> {
+    let mut total = Vec::<Minutes>::with_capacity(3);
+
+    let valley = &map_data.clone().sim();
+
+    let end_point = Loc {
+        x: map_data.size.x - 2,
+        y: map_data.size.y - 1,
+    };
+
+    let start_point = Node(Loc { x: 1, y: 0 }, 0_u16);
+
+    let ans = find_path(valley, start_point, end_point)?;
+
+    total.push(ans.1);
+
+    // Go Back
+    let start_point: Node = ans.0;
+
+    let end_point = Loc { x: 1, y: 0 };
+
+    let ans = find_path(valley, start_point, end_point)?;
+
+    total.push(ans.1);
+
+    // Go Back Again
+    let start_point: Node = ans.0;
+
+    let end_point = Loc {
+        x: map_data.size.x - 2,
+        y: map_data.size.y - 1,
+    };
+
+    let ans = find_path(valley, start_point, end_point)?;
+
+    total.push(ans.1);
+
+    total
+        .iter()
+        .try_fold(0, |sum: Minutes, &t| sum.checked_add(t))
+}
+
+/// Valley of `size` rows, four times as wide, with a blizzard on about a third
+/// of the tiles. None blow up or down through the openings and the three
+/// trips of part 2 can be made.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let height = size.clamp(3, 27) - 2;
+    let width = 4 * height;
+    loop {
+        let mut out = format!("#.{}\n", "#".repeat(width));
+        for _ in 0..height {
+            out.push('#');
+            for x in 1..=width {
+                let blizzards: &[char] = if x == 1 || x == width {
+                    &['<', '>']
+                } else {
+                    &['<', '>', '^', 'v']
+                };
+                out.push(if rng.random_bool(0.3) {
+                    *blizzards.choose(rng).unwrap()
+                } else {
+                    '.'
+                });
+            }
+            out.push_str("#\n");
+        }
+        out.push_str(&format!("{}.#\n", "#".repeat(width)));
+
+        let sim = parse(out.as_bytes()).unwrap();
+        let valley = sim.clone().sim();
+        let (start, end) = (
+            Loc { x: 1, y: 0 },
+            Loc {
+                x: sim.size.x - 2,
+                y: sim.size.y - 1,
+            },
+        );
+        let trips = find_path(&valley, Node(start, 0), end)
+            .and_then(|(there, _)| find_path(&valley, there, start))
+            .and_then(|(back, _)| find_path(&valley, back, end));
+        if trips.is_some() {
+            return out;
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+type Loc = aoc_common::Loc<u8>;
+
+/// The valley is at most 255 by 255.
+fn to_loc(loc: aoc_common::Loc<usize>) -> Loc {
+    Loc {
+        x: loc.x as u8,
+        y: loc.y as u8,
+    }
+}
+
+fn from_loc(loc: Loc) -> aoc_common::Loc<usize> {
+    aoc_common::Loc::new(usize::from(loc.x), usize::from(loc.y))
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Wall,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Blizzard {
+    pos: Loc,
+    dir: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub struct Sim {
+    blizzards: Vec<Blizzard>,
+    size: Loc,
+}
+
+impl Sim {
+    fn new(input: &[u8]) -> Result<Self, ParseError> {
+        let mut sim = Sim {
+            blizzards: Vec::with_capacity(input.len()),
+            size: Loc { x: 0, y: 0 },
+        };
+
+        let valley = Grid::parse(
+            input,
+            |c| match c {
+                b'.' => Some(None),
+                b'^' => Some(Some(Direction::Up)),
+                b'v' => Some(Some(Direction::Down)),
+                b'<' => Some(Some(Direction::Left)),
+                b'>' => Some(Some(Direction::Right)),
+                b'#' => Some(Some(Direction::Wall)),
+                _ => None,
+            },
+            "expected a valley tile",
+        )?;
+        let size_x = valley.width();
+        if !(3..256).contains(&size_x) || !(3..256).contains(&valley.height()) {
+            return Err(ParseError::at(
+                input,
+                0,
+                "valley has to be 3 to 255 tiles wide and high",
+            ));
+        }
+        // Entrance top left, exit bottom right.
+        let (first, last) = (lines(input).next(), lines(input).last());
+        for gap in [
+            &first.unwrap()[1..2],
+            &last.unwrap()[size_x - 2..size_x - 1],
+        ] {
+            if gap != b"." {
+                return Err(ParseError::at_slice(
+                    input,
+                    gap,
+                    "expected an opening in the wall",
+                ));
+            }
+        }
+
+        for idx in 0..valley.len() {
+            if let Some(dir) = valley[idx] {
+                sim.blizzards.push(Blizzard {
+                    pos: to_loc(valley.loc(idx)),
+                    dir,
+                });
+            }
+        }
+
+        sim.size = to_loc(aoc_common::Loc::new(size_x, valley.height()));
+
+        debug!(
+            "blizzards len {} size = {}",
+            sim.blizzards.len(),
+            sim.blizzards.len() * std::mem::size_of::<Blizzard>()
+        );
+
+        Ok(sim)
+    }
+
+    fn blizzards_next(&mut self) {
+        for blis in &mut self.blizzards {
+            match blis.dir {
+                Direction::Up => {
+                    blis.pos.y -= 1;
+                    if blis.pos.y == 0 {
+                        blis.pos.y = self.size.y - 2;
+                    }
+                }
+                Direction::Down => {
+                    blis.pos.y += 1;
+                    if blis.pos.y == self.size.y - 1 {
+                        blis.pos.y = 1;
+                    }
+                }
+                Direction::Left => {
+                    blis.pos.x -= 1;
+                    if blis.pos.x == 0 {
+                        blis.pos.x = self.size.x - 2;
+                    }
+                }
+                Direction::Right => {
+                    blis.pos.x += 1;
+                    if blis.pos.x == self.size.x - 1 {
+                        blis.pos.x = 1;
+                    }
+                }
+                Direction::Wall => (),
+            }
+        }
+    }
+
+    /// Tiles without a blizzard or wall, the side columns are never empty.
+    fn get_empty_spots(&self) -> Grid<bool> {
+        let Loc { x, y } = self.size;
+        let mut empty_spots = Grid::new(usize::from(x), usize::from(y), true);
+
+        for y in 0..empty_spots.height() {
+            let row = empty_spots.row_mut(y);
+            row[0] = false;
+            row[row.len() - 1] = false;
+        }
+        for blis in &self.blizzards {
+            empty_spots[from_loc(blis.pos)] = false;
+        }
+        empty_spots
+    }
+
+    /// Empty spots of every minute in the blizzard cycle.
+    fn sim(&mut self) -> Vec<Grid<bool>> {
+        let mut field_size = self.size;
+        field_size.x -= 2;
+        field_size.y -= 2;
+        let step_cycle_number = (Minutes::from(field_size.x)).lcm(&(Minutes::from(field_size.y)));
+
+        let mut valley = Vec::with_capacity(usize::from(step_cycle_number));
+        valley.push(self.get_empty_spots());
+
+        for _ in 1..step_cycle_number {
+            self.blizzards_next();
+            valley.push(self.get_empty_spots());
+        }
+        debug!("cycle of {step_cycle_number} minutes");
+
+        valley
+    }
+}
+
+fn find_path(valley: &[Grid<bool>], start_point: Node, end_point: Loc) -> Option<(Node, u16)> {
+    let neighbours = |&Node(loc, minutes): &Node| {
+        let min = (usize::from(minutes) + 1) % valley.len();
+        let empty_spots = &valley[min];
+        let loc = from_loc(loc);
+
+        // Move or wait.
+        empty_spots
+            .neighbours4(loc)
+            .chain([loc])
+            .filter(|&spot| empty_spots[spot])
+            .map(move |spot| Node(to_loc(spot), min as Minutes))
+    };
+
+    let paths = bfs(start_point, neighbours, |node| node.0 == end_point);
+    let distance = Minutes::try_from(paths.goal_distance()?).ok()?;
+
+    Some((paths.goal()?, distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_example1() {
+        let mut total = 0;
+
+        let mut sim = Sim::new(INPUT).unwrap();
+
+        let valley = sim.sim();
+
+        let end_point = Loc {
+            x: sim.size.x - 2,
+            y: sim.size.y - 1,
+        };
+
+        let start_point = Node(Loc { x: 1, y: 0 }, 0_u16);
+
+        let ans = find_path(&valley, start_point, end_point);
+
+        assert_eq!(ans, Some((Node(end_point, 6), 18)));
+
+        total += ans.unwrap().1;
+
+        // Go Back
+        let start_point = ans.unwrap().0;
+
+        let end_point = Loc { x: 1, y: 0 };
+
+        let ans = find_path(&valley, start_point, end_point);
+
+        assert_eq!(ans, Some((Node(end_point, 5), 23)));
+
+        total += ans.unwrap().1;
+
+        // Go Back Again
+        let start_point: Node = ans.unwrap().0;
+
+        let end_point = Loc {
+            x: sim.size.x - 2,
+            y: sim.size.y - 1,
+        };
+
+        let ans = find_path(&valley, start_point, end_point);
+
+        assert_eq!(ans, Some((Node(end_point, 6), 13)));
+
+        total += ans.unwrap().1;
+
+        assert_eq!(total, 54);
+
+        assert_eq!(part1(&parse(INPUT).unwrap()), Some(18));
+        assert_eq!(part2(&parse(INPUT).unwrap()), Some(54));
+    }
+
+    #[test]
+    fn test_no_path() {
+        let sim = parse(b"#.###\n#>>>#\n###.#").unwrap();
+        assert_eq!(part1(&sim), None);
+        assert_eq!(part2(&sim), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(b"#.###\n#>x.#\n###.#").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+        let e = parse(b"#.###\n#>..#\n#####").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 4));
+        let e = parse(b"#.###\n#>.#\n###.#").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (2, "rows differ in length"));
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(24);
+        for size in [1, 8, 15] {
+            let sim = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(usize::from(sim.size.y), size.clamp(3, 27));
+            assert!(part1(&sim).unwrap() < part2(&sim).unwrap());
+        }
+    }
+}
@@ -0,0 +1,243 @@
+// #![deny(clippy::pedantic)]
+use aoc_common::{lines, Answer, ParseError, Solution};
+use rand::{seq::IndexedRandom, Rng, RngCore};
+use std::ops::{Add, AddAssign};
+
+type SnafuValue = i64;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct SNAFU(SnafuValue);
+
+impl Add for SNAFU {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for SNAFU {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl PartialEq<SnafuValue> for SNAFU {
+    fn eq(&self, other: &SnafuValue) -> bool {
+        self.0 == *other
+    }
+}
+
+impl SNAFU {
+    fn from(number: &[u8]) -> Self {
+        // Wrapping, so numbers near the ends of the range don't overflow on
+        // the way; the result is right whenever it fits.
+        let total = number.iter().fold(0, |total: SnafuValue, num| {
+            let number: SnafuValue = match num {
+                b'-' => -1,
+                b'=' => -2,
+                o => SnafuValue::from(o - b'0'),
+            };
+            total.wrapping_mul(5).wrapping_add(number)
+        });
+        Self(total)
+    }
+
+    fn to_snafu(&self) -> String {
+        let mut snafu = Vec::with_capacity(28);
+        // Wider, so `num - digit` can't overflow at the ends of the range.
+        let mut num = i128::from(self.0);
+
+        loop {
+            let digit = (num + 2).rem_euclid(5) - 2;
+            snafu.push(match digit {
+                -2 => b'=',
+                -1 => b'-',
+                0 => b'0',
+                1 => b'1',
+                _ => b'2',
+            });
+            num = (num - digit) / 5;
+            if num == 0 {
+                break;
+            }
+        }
+
+        snafu.reverse();
+        String::from_utf8(snafu).unwrap()
+    }
+}
+
+/// One SNAFU number per line.
+pub fn parse(input: &[u8]) -> Result<Vec<SNAFU>, ParseError> {
+    lines(input)
+        .map(|line| {
+            if line.is_empty() || line.len() > 20 {
+                return Err(ParseError::at_slice(
+                    input,
+                    line,
+                    "expected 1 to 20 SNAFU digits",
+                ));
+            }
+            match line.iter().position(|v| !b"=-012".contains(v)) {
+                Some(p) => Err(ParseError::at_slice(
+                    input,
+                    &line[p..=p],
+                    "expected a SNAFU digit",
+                )),
+                None => Ok(SNAFU::from(line)),
+            }
+        })
+        .collect()
+}
+
+/// Sum of all numbers, in SNAFU.
+pub fn part1(numbers: &[SNAFU]) -> String {
+    let mut sum = SNAFU(0);
+    for val in numbers {
+        sum.0 += val.0;
+    }
+    sum.to_snafu()
+}
+
+/// `size` SNAFU numbers of up to 15 digits.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        out.push(*['1', '2'].choose(rng).unwrap());
+        for _ in 1..rng.random_range(1..=15) {
+            out.push(*['=', '-', '0', '1', '2'].choose(rng).unwrap());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, _input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{parse, part1, SnafuValue, SNAFU};
+    use proptest::prelude::*;
+
+    const INPUT_TEST: &[(&[u8], SnafuValue)] = &[
+        (b"1=-0-2", 1747),
+        (b"12111", 906),
+        (b"2=0=", 198),
+        (b"21", 11),
+        (b"2=01", 201),
+        (b"111", 31),
+        (b"20012", 1257),
+        (b"112", 32),
+        (b"1=-1=", 353),
+        (b"1-12", 107),
+        (b"12", 7),
+        (b"1=", 3),
+        (b"122", 37),
+    ];
+
+    const INPUT_DECIMAL_TO_SNAFU: &[(SnafuValue, &[u8])] = &[
+        (0, b"0"),
+        (1, b"1"),
+        (2, b"2"),
+        (3, b"1="),
+        (4, b"1-"),
+        (5, b"10"),
+        (6, b"11"),
+        (7, b"12"),
+        (8, b"2="),
+        (9, b"2-"),
+        (10, b"20"),
+        (15, b"1=0"),
+        (20, b"1-0"),
+        (2022, b"1=11-2"),
+        (12345, b"1-0---0"),
+        (314159265, b"1121-1110-1=0"),
+    ];
+
+    #[test]
+    fn test_snafu_to_decimal() {
+        let mut sum = SNAFU(0);
+        for val in INPUT_TEST {
+            let num = SNAFU::from(val.0);
+            assert_eq!(num, val.1);
+            sum += num;
+        }
+        assert_eq!(sum, 4890);
+
+        for val in INPUT_DECIMAL_TO_SNAFU {
+            let num = SNAFU::from(val.1);
+            assert_eq!(num, val.0);
+        }
+    }
+
+    #[test]
+    fn test_decimal_to_snafu() {
+        for val in INPUT_DECIMAL_TO_SNAFU {
+            let num = SNAFU(val.0);
+            assert_eq!(num.to_snafu(), String::from_utf8_lossy(val.1));
+        }
+        assert_eq!(SNAFU(4890).to_snafu(), "2=-1=0");
+        for x in [SnafuValue::MIN, SnafuValue::MAX] {
+            assert_eq!(SNAFU::from(SNAFU(x).to_snafu().as_bytes()), x);
+        }
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let input = INPUT_TEST
+            .iter()
+            .map(|v| String::from_utf8_lossy(v.0))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(part1(&parse(input.as_bytes()).unwrap()), "2=-1=0");
+
+        let e = parse(b"1=-0-2\n12311").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "3"));
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(25);
+        for size in [1, 100] {
+            let numbers = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(numbers.len(), size);
+            let sum: SnafuValue = numbers.iter().map(|n| n.0).sum();
+            assert_eq!(SNAFU::from(part1(&numbers).as_bytes()), sum);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_snafu_round_trip(x in any::<SnafuValue>()) {
+            prop_assert_eq!(SNAFU::from(SNAFU(x).to_snafu().as_bytes()), x);
+        }
+    }
+}
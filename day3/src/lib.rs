@@ -0,0 +1,188 @@
+use aoc_common::{lines, Answer, ParseError, Solution};
+use log::trace;
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng, RngCore,
+};
+
+fn slice_to_bin(input: &[u8]) -> u64 {
+    input
+        .iter()
+        .map(|v| if *v >= b'a' { v - b'a' } else { v - b'A' + 26 })
+        .fold(0, |sum, x| sum | (1 << u64::from(x)))
+}
+
+/// One rucksack per line.
+pub fn parse(input: &[u8]) -> Result<Vec<&[u8]>, ParseError> {
+    lines(input)
+        .map(
+            |line| match line.iter().position(|c| !c.is_ascii_alphabetic()) {
+                Some(p) => Err(ParseError::at_slice(
+                    input,
+                    &line[p..=p],
+                    "expected an item letter",
+                )),
+                None if line.is_empty() || line.len() % 2 != 0 => Err(ParseError::at_slice(
+                    input,
+                    line,
+                    "expected an even number of items",
+                )),
+                None => Ok(line),
+            },
+        )
+        .collect()
+}
+
+pub fn part1(rucksacks: &[&[u8]]) -> u32 {
+    let mut total_score = 0;
+
+    for (line, data) in rucksacks.iter().enumerate() {
+        // assert!(data.is_empty());
+        // assert!(data.len() & 1 == 0);
+        let (c1, c2) = data.split_at(data.len() / 2);
+        let c1 = slice_to_bin(c1);
+        let c2 = slice_to_bin(c2);
+        let common = c1 & c2;
+        let score = 1 + common.trailing_zeros();
+        total_score += score;
+        trace!(
+            "{line}: {} {common:8x} score {score}",
+            core::str::from_utf8(data).unwrap()
+        );
+    }
+    total_score
+}
+
+pub fn part2(rucksacks: &[&[u8]]) -> u32 {
+    let mut total_score = 0;
+
+    for (line, group) in rucksacks.chunks_exact(3).enumerate() {
+        let common = group
+            .iter()
+            .fold(u64::MAX, |common, d| common & slice_to_bin(d));
+
+        let score = 1 + common.trailing_zeros();
+        total_score += score;
+        trace!("{line}: {common:8x} score {score}");
+    }
+    total_score
+}
+
+/// Rucksacks in groups of three, `size` of them rounded up. Each one has a
+/// single item type in both compartments and each group a single badge.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut items: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+    let mut out = String::new();
+    for _ in 0..size.div_ceil(3).max(1) {
+        items.shuffle(rng);
+        let (badge, others) = items.split_last().unwrap();
+        // Every elf of the group gets their own 17 item types.
+        for own in others.chunks(17) {
+            let (both, own) = own.split_first().unwrap();
+            let (left_only, right_only) = own.split_at(8);
+            let half = rng.random_range(2..=9);
+
+            let mut left = vec![*both, *badge];
+            let mut right = vec![*both];
+            while left.len() < half {
+                left.push(*left_only.choose(rng).unwrap());
+            }
+            while right.len() < half {
+                right.push(*right_only.choose(rng).unwrap());
+            }
+            left.shuffle(rng);
+            right.shuffle(rng);
+
+            left.append(&mut right);
+            out.push_str(core::str::from_utf8(&left).unwrap());
+            out.push('\n');
+        }
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, slice_to_bin};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_slice_to_bin() {
+        assert_eq!(slice_to_bin(b"a"), 1 << 0);
+        assert_eq!(slice_to_bin(b"z"), 1 << 25);
+        assert_eq!(slice_to_bin(b"A"), 1 << 26);
+        assert_eq!(slice_to_bin(b"Z"), 1 << 51);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&[b"aa"]), 1);
+        assert_eq!(part1(&[b"zz"]), 26);
+        assert_eq!(part1(&[b"AA"]), 27);
+        assert_eq!(part1(&[b"ZZ"]), 52);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let ts = part1(&parse(INPUT).unwrap());
+        assert_eq!(ts, 157);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()), 70);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = String::from_utf8_lossy(INPUT).replace('\n', "\r\n") + "\r\n";
+        let list = parse(input.as_bytes()).unwrap();
+        assert_eq!((part1(&list), part2(&list)), (157, 70));
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(3);
+        for size in [3, 100] {
+            let input = super::generate(&mut rng, size);
+            let rucksacks = parse(input.as_bytes()).unwrap();
+            assert_eq!(rucksacks.len(), size.div_ceil(3) * 3);
+            for sack in &rucksacks {
+                let (c1, c2) = sack.split_at(sack.len() / 2);
+                assert_eq!((slice_to_bin(c1) & slice_to_bin(c2)).count_ones(), 1);
+            }
+            for group in rucksacks.chunks(3) {
+                let common = group.iter().fold(u64::MAX, |c, d| c & slice_to_bin(d));
+                assert_eq!(common.count_ones(), 1);
+            }
+            assert!(part1(&rucksacks) > 0 && part2(&rucksacks) > 0);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use aoc_common::{lines, try_slice_to_number, Answer, ParseError, Solution};
+use log::{debug, trace};
+use rand::{Rng, RngCore};
+use std::fmt::Write;
+
+/// Section assignment pairs, one `[start1, end1, start2, end2]` per line.
+pub fn parse(input: &[u8]) -> Result<Vec<Vec<u8>>, ParseError> {
+    lines(input)
+        .map(|line| parse_to_pair(input, line))
+        .collect()
+}
+
+pub fn part1(assignments: &[Vec<u8>]) -> u32 {
+    let mut total_score = 0;
+    let mut total_false = 0;
+
+    for pairs in assignments {
+        let overlapp = pair_fully_overlap(pairs);
+        trace!("{pairs:?} = {overlapp:?}");
+        if overlapp {
+            total_score += 1
+        } else {
+            total_false += 1
+        };
+    }
+    debug!("total false {total_false}");
+    total_score
+}
+
+pub fn part2(assignments: &[Vec<u8>]) -> u32 {
+    let mut total_score = 0;
+    let mut total_false = 0;
+
+    for pairs in assignments {
+        let overlapp = pair_overlap(pairs);
+        trace!("{pairs:?} = {overlapp:?}");
+        if overlapp {
+            total_score += 1
+        } else {
+            total_false += 1
+        };
+    }
+    debug!("total false {total_false}");
+    total_score
+}
+
+fn parse_to_pair(input: &[u8], line: &[u8]) -> Result<Vec<u8>, ParseError> {
+    let pair = line
+        .split(|v| *v == b'-' || *v == b',')
+        .map(|v| {
+            try_slice_to_number::<u8>(v)
+                .ok_or_else(|| ParseError::at_slice(input, v, "expected a section number"))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    if pair.len() != 4 {
+        return Err(ParseError::at_slice(input, line, "expected `a-b,c-d`"));
+    }
+    Ok(pair)
+}
+
+/// `size` pairs of section assignments.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let mut section = || {
+            let start = rng.random_range(1..=99);
+            (start, rng.random_range(start..=99))
+        };
+        let ((a, b), (c, d)) = (section(), section());
+        writeln!(out, "{a}-{b},{c}-{d}").unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+// [1,100,2,99]
+// [2,99,1,100] 2 >= 1 = false, 1 >= 2 = true , 100 <= 99
+fn pair_fully_overlap(input: &[u8]) -> bool {
+    if let Some(data) = input.get(0..=3) {
+        if data[0] <= data[2] && data[1] >= data[3] {
+            return true;
+        }
+        if data[2] <= data[0] && data[3] >= data[1] {
+            return true;
+        }
+        false
+    } else {
+        panic!("{input:?}");
+    }
+}
+
+// [1,100,2,99]
+// [2,99,1,100] 2 >= 1 = false, 1 >= 2 = true , 100 <= 99
+
+// [2, 4, 6, 8]
+fn pair_overlap(input: &[u8]) -> bool {
+    if let Some(data) = input.get(0..=3) {
+        if (data[0]..=data[1]).contains(&data[2]) {
+            return true;
+        };
+        if (data[0]..=data[1]).contains(&data[3]) {
+            return true;
+        };
+        if (data[2]..=data[3]).contains(&data[0]) {
+            return true;
+        };
+        if (data[2]..=data[3]).contains(&data[1]) {
+            return true;
+        };
+        false
+    } else {
+        panic!("{input:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pair_overlap;
+
+    use super::{lines, pair_fully_overlap, parse, parse_to_pair, part1, part2};
+    use aoc_common::slice_to_number;
+    use proptest::prelude::*;
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_slice_to_number() {
+        assert_eq!(slice_to_number::<u8>(b"0"), 0);
+        assert_eq!(slice_to_number::<u8>(b"255"), 255);
+        assert_eq!(slice_to_number::<u8>(b"0255"), 255);
+        assert_eq!(slice_to_number::<u8>(b"000"), 0);
+    }
+
+    #[test]
+    fn test_slice_to_pair() {
+        let mut line = lines(INPUT);
+
+        let data = line.next().unwrap();
+        assert_eq!(parse_to_pair(INPUT, data).unwrap(), vec![2, 4, 6, 8]);
+        let data = line.next().unwrap();
+        assert_eq!(parse_to_pair(INPUT, data).unwrap(), vec![2, 3, 4, 5]);
+
+        let e = parse_to_pair(b"2-4,6", b"2-4,6").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "2-4,6"));
+        let e = parse_to_pair(b"2-4,6-x", b"2-4,6-x").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (7, "x"));
+    }
+    #[test]
+    fn test_example_full_overlap() {
+        assert!(pair_fully_overlap(&[6, 6, 4, 6]));
+        assert!(pair_fully_overlap(&[4, 6, 6, 6]));
+        assert!(pair_fully_overlap(&[1, 100, 2, 99]));
+        assert!(pair_fully_overlap(&[2, 99, 1, 100]));
+
+        assert!(!pair_fully_overlap(&[6, 7, 4, 6]));
+        assert!(!pair_fully_overlap(&[4, 6, 6, 7]));
+        assert!(!pair_fully_overlap(&[1, 98, 2, 99]));
+        assert!(!pair_fully_overlap(&[2, 99, 1, 98]));
+
+        assert!(pair_fully_overlap(&[4, 99, 4, 47]));
+
+        let ans = [false, false, false, true, true, false];
+        let line = lines(INPUT);
+
+        for (&a, l) in ans.iter().zip(line) {
+            let pairs = parse_to_pair(INPUT, l).unwrap();
+            println!("{:?}", pairs);
+            assert_eq!(a, pair_fully_overlap(&pairs));
+        }
+    }
+
+    #[test]
+    fn test_example_overlap() {
+        assert!(pair_overlap(&[6, 6, 4, 6]));
+        assert!(pair_overlap(&[4, 6, 6, 6]));
+        assert!(pair_overlap(&[1, 100, 2, 99]));
+        assert!(pair_overlap(&[2, 99, 1, 100]));
+
+        assert!(pair_overlap(&[6, 7, 4, 6]));
+        assert!(pair_overlap(&[4, 6, 6, 7]));
+        assert!(pair_overlap(&[1, 98, 2, 99]));
+        assert!(pair_overlap(&[2, 99, 1, 98]));
+
+        assert!(pair_overlap(&[4, 99, 4, 47]));
+
+        let ans = [false, false, true, true, true, true];
+        let line = lines(INPUT);
+
+        for (&a, l) in ans.iter().zip(line) {
+            let pairs = parse_to_pair(INPUT, l).unwrap();
+            println!("{:?}", pairs);
+            assert_eq!(a, pair_overlap(&pairs));
+        }
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let ts = part1(&parse(INPUT).unwrap());
+        assert_eq!(ts, 2);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = String::from_utf8_lossy(INPUT).replace('\n', "\r\n") + "\r\n";
+        let pairs = parse(input.as_bytes()).unwrap();
+        assert_eq!((part1(&pairs), part2(&pairs)), (2, 4));
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(4);
+        for size in [1, 100] {
+            let input = super::generate(&mut rng, size);
+            let assignments = parse(input.as_bytes()).unwrap();
+            assert_eq!(assignments.len(), size);
+            assert!(part1(&assignments) <= part2(&assignments));
+        }
+    }
+
+    fn section() -> impl Strategy<Value = (u8, u8)> {
+        (any::<u8>(), any::<u8>()).prop_map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_full_overlap_is_overlap((a, b) in section(), (c, d) in section()) {
+            let pair = [a, b, c, d];
+            prop_assert!(!pair_fully_overlap(&pair) || pair_overlap(&pair));
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use aoc_common::{lines, try_slice_to_number, Answer, ParseError, Solution};
+use log::trace;
+use rand::{Rng, RngCore};
+use std::fmt::Write;
+
+/// The starting stacks, bottom crate first.
+const START: [&[u8]; 9] = [
+    b"BZT",
+    b"VHTDN",
+    b"BFMD",
+    b"TJGWVQM",
+    b"WDGPVFQM",
+    b"VZQGHFS",
+    b"ZSNRLTCW",
+    b"ZHWDJNRM",
+    b"MQLFDS",
+];
+
+/// Only the move list, the starting stacks are built in.
+pub fn parse(input: &[u8]) -> Result<Vec<Instruction>, ParseError> {
+    lines(input)
+        .map(|line| Instruction::from(input, line))
+        .collect()
+}
+
+/// Top crates when moved one at a time, `None` when a move takes more crates
+/// than its stack holds or a stack ends up empty.
+pub fn part1(instructions: &[Instruction]) -> Option<String> {
+    let mut crates = Containers::start();
+
+    for ins in instructions {
+        trace!("line: {ins:?}");
+        crates.job(*ins)?
+    }
+
+    crates.finish()
+}
+
+/// Top crates when moved all at once, `None` like [`part1`].
+pub fn part2(instructions: &[Instruction]) -> Option<String> {
+    let mut crates = Containers::start();
+
+    for ins in instructions {
+        trace!("line: {ins:?}");
+        crates.job_mutli(*ins)?
+    }
+
+    crates.finish()
+}
+
+/// `size` moves on the built in stacks that never empty a stack.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut heights = START.map(<[u8]>::len);
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let from = loop {
+            let from = rng.random_range(0..heights.len());
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.random_range(1..heights.len())) % heights.len();
+        let n = rng.random_range(1..heights[from]);
+        heights[from] -= n;
+        heights[to] += n;
+        writeln!(out, "move {n} from {} to {}", from + 1, to + 1).unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    n: u8,
+    from: u8,
+    to: u8,
+}
+
+impl Instruction {
+    /// `move <n> from <stack> to <stack>`, `line` is part of `input`.
+    fn from(input: &[u8], line: &[u8]) -> Result<Self, ParseError> {
+        let syntax = || ParseError::at_slice(input, line, "expected `move n from a to b`");
+        let words: Vec<&[u8]> = line.split(|v| *v == b' ').collect();
+        let [b"move", n, b"from", from, b"to", to] = words[..] else {
+            return Err(syntax());
+        };
+
+        let number = |v: &[u8]| {
+            try_slice_to_number::<u8>(v)
+                .ok_or_else(|| ParseError::at_slice(input, v, "expected a number"))
+        };
+        let stack = |v: &[u8]| match number(v)? {
+            n @ 1..=9 => Ok(n),
+            _ => Err(ParseError::at_slice(input, v, "no such stack")),
+        };
+
+        Ok(Self {
+            n: number(n)?,
+            from: stack(from)?,
+            to: stack(to)?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Containers {
+    state: Vec<Vec<u8>>,
+}
+
+impl Containers {
+    fn start() -> Self {
+        Self {
+            state: START.iter().map(|stack| stack.to_vec()).collect(),
+        }
+    }
+
+    /// `None` when the stack runs out of crates.
+    fn job(&mut self, ins: Instruction) -> Option<()> {
+        for _ in 0..ins.n {
+            let from = usize::from(ins.from) - 1;
+            let krat = self.state[from].pop()?;
+            let to = usize::from(ins.to) - 1;
+            self.state[to].push(krat);
+        }
+        Some(())
+    }
+
+    /// `None` like [`Containers::job`].
+    fn job_mutli(&mut self, ins: Instruction) -> Option<()> {
+        let from = usize::from(ins.from) - 1;
+        let start = self.state[from].len().checked_sub(usize::from(ins.n))?;
+        let krat = self.state[from].drain(start..).as_slice().to_vec();
+        let to = usize::from(ins.to) - 1;
+        self.state[to].extend_from_slice(&krat);
+        Some(())
+    }
+
+    /// The top crates, `None` when a stack is empty.
+    fn finish(&self) -> Option<String> {
+        let mut letters = Vec::with_capacity(self.state.len());
+        for stack in &self.state {
+            letters.push(*stack.last()?);
+        }
+
+        String::from_utf8(letters).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, Containers, Instruction};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_slice_to_number() {
+        let mut line = lines(INPUT);
+
+        let data = line.next().unwrap();
+        assert_eq!(
+            Instruction::from(INPUT, data).unwrap(),
+            Instruction {
+                n: 1,
+                from: 2,
+                to: 1
+            }
+        );
+        let data = line.next().unwrap();
+        assert_eq!(
+            Instruction::from(INPUT, data).unwrap(),
+            Instruction {
+                n: 3,
+                from: 1,
+                to: 3
+            }
+        );
+        let data = line.next().unwrap();
+        assert_eq!(
+            Instruction::from(INPUT, data).unwrap(),
+            Instruction {
+                n: 2,
+                from: 2,
+                to: 1
+            }
+        );
+        let data = line.next().unwrap();
+        assert_eq!(
+            Instruction::from(INPUT, data).unwrap(),
+            Instruction {
+                n: 1,
+                from: 1,
+                to: 2
+            }
+        );
+        assert!(line.next().is_none());
+
+        let e = Instruction::from(b"move 1 from 2 to x", b"move 1 from 2 to x").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (18, "x"));
+        let e = Instruction::from(b"move 1 from 0 to 1", b"move 1 from 0 to 1").unwrap_err();
+        assert_eq!((e.column, e.msg.as_str()), (13, "no such stack"));
+        let e = Instruction::from(b"move 1 to 2", b"move 1 to 2").unwrap_err();
+        assert_eq!(e.text, "move 1 to 2");
+    }
+
+    #[test]
+    fn test_example() {
+        let line = lines(INPUT);
+
+        let mut crates = Containers {
+            state: vec![vec![b'Z', b'N'], vec![b'M', b'C', b'D'], vec![b'P']],
+        };
+
+        for data in line {
+            crates.job(Instruction::from(INPUT, data).unwrap()).unwrap()
+        }
+
+        println!("{crates:?}");
+
+        assert_eq!(
+            crates,
+            Containers {
+                state: vec![vec![b'C'], vec![b'M'], vec![b'P', b'D', b'N', b'Z']]
+            }
+        );
+        assert_eq!(crates.finish(), Some("CMZ".to_owned()));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let mut crates = Containers {
+            state: vec![vec![b'Z', b'N'], vec![b'M', b'C', b'D'], vec![b'P']],
+        };
+
+        for data in lines(INPUT) {
+            crates
+                .job_mutli(Instruction::from(INPUT, data).unwrap())
+                .unwrap()
+        }
+
+        println!("{crates:?}");
+
+        assert_eq!(
+            crates,
+            Containers {
+                state: vec![b"M".to_vec(), vec![b'C'], b"PZND".to_vec()]
+            }
+        );
+        assert_eq!(crates.finish(), Some("MCD".to_owned()));
+    }
+
+    #[test]
+    fn test_too_many() {
+        let instructions = super::parse(b"move 4 from 1 to 2\n").unwrap();
+        assert_eq!(super::part1(&instructions), None);
+        assert_eq!(super::part2(&instructions), None);
+        let instructions = super::parse(b"move 3 from 1 to 2\n").unwrap();
+        assert_eq!(super::part1(&instructions), None);
+        assert_eq!(super::part2(&instructions), None);
+        let instructions = super::parse(b"move 0 from 1 to 2\n").unwrap();
+        assert_eq!(super::part1(&instructions).unwrap(), "TNDMMSWMS");
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(5);
+        for size in [1, 100] {
+            let input = super::generate(&mut rng, size);
+            let instructions = super::parse(input.as_bytes()).unwrap();
+            assert_eq!(instructions.len(), size);
+            assert_eq!(super::part1(&instructions).unwrap().len(), 9);
+            assert_eq!(super::part2(&instructions).unwrap().len(), 9);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_common::{lines, Answer, ParseError, Solution};
+use log::trace;
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+/// The datastream buffer, the first line of the input.
+pub fn parse(input: &[u8]) -> Result<&[u8], ParseError> {
+    let mut line = lines(input);
+
+    let data = line.next().unwrap_or_default();
+    match data.iter().position(|c| !c.is_ascii_lowercase()) {
+        Some(p) => Err(ParseError::at_slice(
+            input,
+            &data[p..=p],
+            "expected a letter",
+        )),
+        None => Ok(data),
+    }
+}
+
+/// `None` when the datastream has no start-of-packet marker.
+pub fn part1(data: &[u8]) -> Option<usize> {
+    detect_sop(data, 4)
+}
+
+/// `None` when the datastream has no start-of-message marker.
+pub fn part2(data: &[u8]) -> Option<usize> {
+    detect_sop(data, 14)
+}
+
+/// About `size` characters that end with a start-of-message marker.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    // Too few letters for a marker to turn up much before the end.
+    let mut data: Vec<u8> = (0..size.saturating_sub(14))
+        .map(|_| rng.random_range(b'a'..=b'l'))
+        .collect();
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    marker.shuffle(rng);
+    data.extend_from_slice(&marker[..14]);
+    data.push(b'\n');
+    String::from_utf8(data).unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(parse(input)?).map_or(Answer::Unsolved, Answer::from))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+fn detect_sop(input: &[u8], n: usize) -> Option<usize> {
+    let mut buf = vec![0_u8; n - 1];
+
+    let mut rep = n;
+
+    for (pos, &c) in input.iter().enumerate() {
+
+        // for p in 0..buf.len() {
+        //     let b = buf[ n - 1 - ((p + k + 1) % n) ];
+        //     if b == c {
+        //         rep = ( n - p ).max(rep);
+        //         println!("\t pos {p}, set rep {rep}");
+        //     }
+        // }
+
+        for (p, b) in buf.iter().enumerate() {
+            if *b == c {
+                rep = (p + 2).max(rep);
+                trace!("\t pos {p}, set rep {rep}");
+            }
+        }
+
+        trace!("{buf:02X?}, {c:02x} {} r{rep} p{pos}", char::from(c));
+
+        buf.remove(0);
+        buf.push(c);
+
+        rep -= 1;
+
+        if rep == 0 {
+            return Some(pos + 1);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_sop, parse};
+
+    const INPUT: [&[u8]; 5] = [
+        include_bytes!("../input/example.txt"),
+        include_bytes!("../input/example2.txt"),
+        include_bytes!("../input/example3.txt"),
+        include_bytes!("../input/example4.txt"),
+        include_bytes!("../input/example5.txt"),
+    ];
+
+    #[test]
+    fn test_detect_sop() {
+        let mut line = INPUT.iter().map(|input| parse(input).unwrap());
+
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 4), Some(7));
+
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 4), Some(5));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 4), Some(6));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 4), Some(10));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 4), Some(11));
+        assert!(line.next().is_none());
+    }
+
+    #[test]
+    fn test_detect_sop_part2() {
+        let mut line = INPUT.iter().map(|input| parse(input).unwrap());
+
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 14), Some(19));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 14), Some(23));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 14), Some(23));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 14), Some(29));
+        let data = line.next().unwrap();
+        assert_eq!(detect_sop(data, 14), Some(26));
+
+        assert_eq!(detect_sop(b"aaaaaaaa", 4), None);
+        assert_eq!(detect_sop(b"abcd", 14), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(6);
+        for size in [1, 14, 1000] {
+            let input = super::generate(&mut rng, size);
+            let data = super::parse(input.as_bytes()).unwrap();
+            assert_eq!(data.len(), size.max(14));
+            assert!(super::part1(data) <= super::part2(data));
+            assert!(super::part2(data).unwrap() <= data.len());
+        }
+    }
+}
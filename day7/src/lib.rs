@@ -0,0 +1,319 @@
+use aoc_common::{as_str, str_lines, Answer, ParseError, Solution};
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::fmt::Write;
+
+const DISK_SPACE: u32 = 70_000_000;
+const DISK_SPACE_NEEDED: u32 = 30_000_000;
+
+pub fn part1(tree: &[Entry]) -> u32 {
+    let sum: u32 = tree
+        .iter()
+        .filter(|e| e.item == Item::Dir && e.size <= 100_000)
+        .map(|e| e.size)
+        .sum();
+    sum
+}
+
+pub fn part2(tree: &[Entry]) -> u32 {
+    let disk_usage = tree[0].size;
+
+    let current_free = DISK_SPACE - disk_usage;
+
+    let size_to_delete = DISK_SPACE_NEEDED - current_free;
+
+    let dir_size: u32 = tree
+        .iter()
+        .filter(|e| e.item == Item::Dir && e.size >= size_to_delete)
+        .map(|e| e.size)
+        .min()
+        .unwrap();
+
+    dir_size
+}
+
+/// Terminal session that lists every directory once, about `size` entries,
+/// filling the disk far enough that part 2 has to free up space.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.clamp(2, 60_000);
+    let mut dirs: Vec<Vec<usize>> = vec![Vec::new()];
+    let mut files: Vec<Vec<u32>> = vec![Vec::new()];
+    for _ in 1..size / 5 {
+        let parent = rng.random_range(0..dirs.len());
+        let dir = dirs.len();
+        dirs[parent].push(dir);
+        dirs.push(Vec::new());
+        files.push(Vec::new());
+    }
+
+    let target = rng.random_range(41_000_000..69_000_000);
+    let largest = (35_000_000 / size as u32).min(300_000);
+    let mut used = 0;
+    for _ in dirs.len()..size - 1 {
+        let file = rng.random_range(1..=largest);
+        files[rng.random_range(0..dirs.len())].push(file);
+        used += file;
+    }
+    files[rng.random_range(0..dirs.len())].push(target - used);
+
+    let mut out = String::from("$ cd /\n");
+    session(rng, &mut out, 0, &dirs, &files);
+    out
+}
+
+/// `$ ls` of `dir` and a visit to each of its subdirectories.
+fn session(
+    rng: &mut dyn RngCore,
+    out: &mut String,
+    dir: usize,
+    dirs: &[Vec<usize>],
+    files: &[Vec<u32>],
+) {
+    let mut listing: Vec<String> = dirs[dir].iter().map(|sub| format!("dir d{sub}")).collect();
+    listing.extend(
+        files[dir]
+            .iter()
+            .enumerate()
+            .map(|(n, size)| format!("{size} f{n}.txt")),
+    );
+    listing.shuffle(rng);
+
+    out.push_str("$ ls\n");
+    for line in listing {
+        writeln!(out, "{line}").unwrap();
+    }
+    for &sub in &dirs[dir] {
+        writeln!(out, "$ cd d{sub}").unwrap();
+        session(rng, out, sub, dirs, files);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(as_str(input)?).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(as_str(input)?)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(as_str(input)?)?).into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Item {
+    Dir,
+    File,
+}
+
+type ParentID = u16;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entry<'a> {
+    name: &'a str,
+    parent: Option<ParentID>,
+    item: Item,
+    size: u32,
+    id: ParentID,
+}
+
+/// Flattened directory tree, the root is the first entry.
+pub fn parse(input: &str) -> Result<Vec<Entry<'_>>, ParseError> {
+    let err = |part: &str, msg: &str| ParseError::at_slice(input.as_bytes(), part.as_bytes(), msg);
+
+    let mut entries = Vec::<Entry>::with_capacity(1000);
+
+    entries.push(Entry {
+        name: "/",
+        parent: None,
+        item: Item::Dir,
+        size: 0,
+        id: 0,
+    });
+
+    let mut path = Vec::<ParentID>::with_capacity(10);
+    path.push(0);
+
+    for line in str_lines(input) {
+        let mut b = line.split_terminator(' ');
+
+        let start = b.next().ok_or_else(|| err(line, "empty line"))?;
+        let mut arg = |msg| b.next().ok_or_else(|| err(&line[line.len()..], msg));
+
+        match start {
+            "$" => match arg("expected a command")? {
+                "ls" => (),
+                "cd" => match arg("expected a directory")? {
+                    "/" => {
+                        path.clear();
+                        path.push(0);
+                    }
+                    ".." => {
+                        if path.len() < 2 {
+                            return Err(err(line, "already at /"));
+                        }
+                        let current_id = path.pop().unwrap() as usize;
+                        let parent_id = path.last().unwrap().to_owned() as usize;
+                        let size = entries[current_id].size;
+
+                        entries[parent_id].size = entries[parent_id]
+                            .size
+                            .checked_add(size)
+                            .ok_or_else(|| err(line, "directory too large"))?;
+                    }
+                    name => {
+                        let parent_id = path.last().cloned();
+                        let parent = entries
+                            .iter()
+                            .find(|e| e.parent == parent_id && e.name == name)
+                            .map(|x| x.id)
+                            .ok_or_else(|| err(name, "unknown directory"))?;
+                        path.push(parent);
+                    }
+                },
+                cmd => return Err(err(cmd, "unknown command")),
+            },
+            "dir" => {
+                let parent = path.last().cloned();
+                let id =
+                    ParentID::try_from(entries.len()).map_err(|_| err(line, "too many entries"))?;
+                entries.push(Entry {
+                    name: arg("expected a name")?,
+                    parent,
+                    item: Item::Dir,
+                    size: 0,
+                    id,
+                })
+            }
+            size => {
+                let parent = path.last().cloned();
+                let size = size
+                    .parse()
+                    .map_err(|_| err(size, "expected a file size"))?;
+                let id =
+                    ParentID::try_from(entries.len()).map_err(|_| err(line, "too many entries"))?;
+
+                let dir = &mut entries[parent.unwrap() as usize];
+                dir.size = dir
+                    .size
+                    .checked_add(size)
+                    .ok_or_else(|| err(line, "directory too large"))?;
+                entries.push(Entry {
+                    name: arg("expected a name")?,
+                    parent,
+                    item: Item::File,
+                    size,
+                    id,
+                })
+            }
+        }
+    }
+
+    loop {
+        if path.len() < 2 {
+            break;
+        }
+        let current_id = path.pop().unwrap() as usize;
+        let parent_id = path.last().unwrap().to_owned() as usize;
+        let size = entries[current_id].size;
+
+        entries[parent_id].size = entries[parent_id]
+            .size
+            .checked_add(size)
+            .ok_or_else(|| err(&input[input.len()..], "directory too large"))?;
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Item, DISK_SPACE, DISK_SPACE_NEEDED};
+
+    const INPUT: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn test_example() {
+        let tree = parse(INPUT).unwrap();
+
+        assert_eq!(tree.len(), 14);
+        assert_eq!(tree[0].size, 48381165);
+
+        let sum: u32 = tree
+            .iter()
+            .filter(|e| e.item == Item::Dir && e.size <= 100_000)
+            .map(|e| e.size)
+            .sum();
+        assert_eq!(sum, 95437);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let tree = parse(INPUT).unwrap();
+
+        assert_eq!(tree.len(), 14);
+
+        let disk_usage = tree[0].size;
+        assert_eq!(disk_usage, 48_381_165);
+
+        let current_free = DISK_SPACE - disk_usage;
+
+        assert_eq!(current_free, 21_618_835);
+
+        let size_to_delete = DISK_SPACE_NEEDED - current_free;
+
+        assert_eq!(size_to_delete, 8_381_165);
+
+        let dir_size: u32 = tree
+            .iter()
+            .filter(|e| e.item == Item::Dir && e.size >= size_to_delete)
+            .map(|e| e.size)
+            .min()
+            .unwrap();
+
+        assert_eq!(dir_size, 24_933_642);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("$ cd /\n$ cd x\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 6, "x"));
+        let e = parse("$ cd /\n12a b\n").unwrap_err();
+        assert_eq!(e.msg, "expected a file size");
+        let e = parse("$ cd /\ndir").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.msg.as_str()),
+            (2, 4, "expected a name")
+        );
+        let e = parse("$ cd /\n4000000000 a\n4000000000 b\n").unwrap_err();
+        assert_eq!((e.line, e.msg.as_str()), (3, "directory too large"));
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::{generate, part1, part2};
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(7);
+        for size in [1, 10, 1000] {
+            let input = generate(&mut rng, size);
+            let tree = parse(&input).unwrap();
+            assert_eq!(tree.len(), size.max(2));
+            assert!(tree[0].size > DISK_SPACE - DISK_SPACE_NEEDED);
+            assert!(tree[0].size < DISK_SPACE);
+            part1(&tree);
+            assert!(part2(&tree) <= tree[0].size);
+        }
+    }
+}
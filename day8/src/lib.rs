@@ -0,0 +1,269 @@
+use aoc_common::{heat, Answer, Grid, Image, Loc, ParseError, Solution};
+use rand::{Rng, RngCore};
+
+/// Tree height map.
+pub fn parse(input: &[u8]) -> Result<Map, ParseError> {
+    Map::new(input)
+}
+
+pub fn part1(map: &Map) -> usize {
+    let dir = map.create_direction_map();
+
+    dir.iter().fold(0, |acc, f| acc + usize::from(f.0 != 0))
+}
+
+pub fn part2(map: &Map) -> usize {
+    let scienic = map.create_scenic_map();
+
+    *scienic.iter().max().unwrap()
+}
+
+/// Forest of `size` by `size` trees.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.max(1);
+    let mut out = String::with_capacity((size + 1) * size);
+    for _ in 0..size {
+        out.extend((0..size).map(|_| char::from(rng.random_range(b'0'..=b'9'))));
+        out.push('\n');
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn image(&self, input: &[u8]) -> Result<Option<Image>, ParseError> {
+        Ok(Some(parse(input)?.heatmap()))
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[repr(u8)]
+enum Side {
+    Top = 1,
+    Right = 2,
+    Bottom = 4,
+    Left = 8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Visable(u8);
+
+impl Visable {
+    fn new() -> Self {
+        Self(0)
+    }
+    fn top(&mut self) {
+        self.0 |= Side::Top as u8;
+    }
+    fn right(&mut self) {
+        self.0 |= Side::Right as u8;
+    }
+    fn bottom(&mut self) {
+        self.0 |= Side::Bottom as u8;
+    }
+    fn left(&mut self) {
+        self.0 |= Side::Left as u8;
+    }
+}
+
+pub struct Map {
+    trees: Grid<u8>,
+}
+
+/// Trees seen from `tree` along `line`, up to and including the first one that
+/// is as high.
+fn viewing_distance<'a>(tree: u8, line: impl Iterator<Item = &'a u8>) -> usize {
+    let mut distance = 0;
+    for &d in line {
+        distance += 1;
+        if d >= tree {
+            break;
+        }
+    }
+    distance
+}
+
+impl Map {
+    fn new(input: &[u8]) -> Result<Self, ParseError> {
+        let trees = Grid::parse(
+            input,
+            |c| c.is_ascii_digit().then(|| c - b'0'),
+            "expected a tree height",
+        )?;
+        if trees.is_empty() {
+            return Err(ParseError::at(input, input.len(), "expected a tree height"));
+        }
+
+        Ok(Map { trees })
+    }
+
+    fn visable(&self, n: usize) -> Visable {
+        let Loc { x, y } = self.trees.loc(n);
+        let tree = self.trees[n];
+        let row = self.trees.row(y);
+        let lower = |d: &u8| *d < tree;
+
+        let mut vis = Visable::new();
+
+        if row[..x].iter().all(lower) {
+            vis.left();
+        }
+        if row[x + 1..].iter().all(lower) {
+            vis.right();
+        }
+        if self.trees.column(x).take(y).all(lower) {
+            vis.top();
+        }
+        if self.trees.column(x).skip(y + 1).all(lower) {
+            vis.bottom();
+        }
+
+        vis
+    }
+
+    fn tree_score(&self, n: usize) -> usize {
+        let Loc { x, y } = self.trees.loc(n);
+        let tree = self.trees[n];
+        let row = self.trees.row(y);
+
+        // Edge trees see nothing in at least one direction, so they score 0.
+        viewing_distance(tree, row[..x].iter().rev())
+            * viewing_distance(tree, row[x + 1..].iter())
+            * viewing_distance(tree, self.trees.column(x).take(y).rev())
+            * viewing_distance(tree, self.trees.column(x).skip(y + 1))
+    }
+
+    fn create_direction_map(&self) -> Vec<Visable> {
+        (0..self.trees.len()).map(|n| self.visable(n)).collect()
+    }
+
+    fn create_scenic_map(&self) -> Vec<usize> {
+        (0..self.trees.len()).map(|n| self.tree_score(n)).collect()
+    }
+
+    /// Scenic scores on a square root scale, the best tree is white.
+    fn heatmap(&self) -> Image {
+        let scores = Grid::from_vec(self.trees.width(), self.create_scenic_map());
+        let max = (0..scores.len()).map(|n| scores[n]).max().unwrap_or(0);
+        Image::from_grid(&scores, |&score| heat(score.isqrt(), max.isqrt()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, Map, Visable};
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_map_new() {
+        let map = Map::new(INPUT).unwrap();
+
+        assert_eq!(map.trees.width(), 5);
+        assert_eq!(map.trees.height(), 5);
+
+        let mut ans = Visable::new();
+        ans.top();
+        ans.left();
+        assert_eq!(map.visable(0), ans);
+
+        let mut ans = Visable::new();
+        ans.top();
+        ans.right();
+        assert_eq!(map.visable(4), ans);
+
+        let mut ans = Visable::new();
+        ans.bottom();
+        ans.right();
+        assert_eq!(map.visable(24), ans);
+
+        let mut ans = Visable::new();
+        ans.bottom();
+        ans.right();
+        ans.left();
+        ans.top();
+        assert_eq!(map.visable(23), ans);
+
+        let mut ans = Visable::new();
+        ans.bottom();
+        ans.top();
+        ans.left();
+        ans.right();
+        assert_eq!(map.visable(10), ans);
+    }
+
+    #[test]
+    fn test_map_direction() {
+        let map = Map::new(INPUT).unwrap();
+
+        let dir = map.create_direction_map();
+
+        assert_eq!(dir.len(), 25);
+
+        let total = dir.iter().fold(0, |acc, f| acc + usize::from(f.0 != 0));
+
+        assert_eq!(total, 21);
+    }
+
+    #[test]
+    fn test_map_scenic() {
+        let map = Map::new(INPUT).unwrap();
+
+        let dir = map.create_scenic_map();
+
+        assert_eq!(dir.len(), 25);
+
+        assert_eq!(dir[7], 4);
+        assert_eq!(dir[17], 8);
+
+        let image = map.heatmap();
+        assert_eq!((image.width(), image.height()), (5, 5));
+        assert_eq!(image.get(2, 3), Some([255, 255, 255]));
+        assert_eq!(image.get(0, 0), Some([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_example() {
+        let map = parse(INPUT).unwrap();
+
+        assert_eq!(part1(&map), 21);
+        assert_eq!(part2(&map), 8);
+
+        let e = parse(b"").err().unwrap();
+        assert_eq!(e.msg, "expected a tree height");
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(8);
+        for size in [1, 5, 99] {
+            let map = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert!(part1(&map) >= (4 * size).saturating_sub(4).max(1));
+            assert!(part1(&map) <= size * size);
+            part2(&map);
+        }
+    }
+}
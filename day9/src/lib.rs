@@ -0,0 +1,423 @@
+use aoc_common::{lines, try_slice_to_number, Answer, Frame, ParseError, Part, Solution};
+use rand::{seq::IndexedRandom, Rng, RngCore};
+use std::{fmt::Write, slice};
+
+pub fn parse(input: &[u8]) -> Result<Vec<Move>, ParseError> {
+    lines(input)
+        .map(|v| {
+            let [dir, b' ', step @ ..] = v else {
+                return Err(ParseError::at_slice(
+                    input,
+                    v,
+                    "expected `<U|D|L|R> <steps>`",
+                ));
+            };
+            let m = match dir {
+                b'U' => EMove::Up,
+                b'L' => EMove::Left,
+                b'R' => EMove::Right,
+                b'D' => EMove::Down,
+                _ => {
+                    return Err(ParseError::at_slice(
+                        input,
+                        &v[..1],
+                        "expected U, D, L or R",
+                    ))
+                }
+            };
+            let step = try_slice_to_number::<u8>(step)
+                .and_then(|s| i8::try_from(s).ok())
+                .ok_or_else(|| ParseError::at_slice(input, step, "expected a step count"))?;
+            Ok(Move(m, step))
+        })
+        .collect()
+}
+
+pub fn part1(steps: &[Move]) -> usize {
+    tail_visits(steps, 2, &mut |_, _| ())
+}
+
+pub fn part2(steps: &[Move]) -> usize {
+    tail_visits(steps, 10, &mut |_, _| ())
+}
+
+/// Spots the tail of a rope of `knots` visits, `show` sees the rope after
+/// every move.
+fn tail_visits(steps: &[Move], knots: usize, show: &mut dyn FnMut(&Sim, usize)) -> usize {
+    let mut moves = rope(steps, knots);
+
+    let mut n = 0;
+    while moves.next().is_some() {
+        show(&moves.sim, n);
+        n += 1;
+    }
+
+    // println!("Max: x{} y{} l{}", sim.m_x, sim.m_y, sim.list.len());
+
+    moves.sim.list.len()
+}
+
+/// A rope of `knots` knots following the head through `steps`, one item per
+/// move.
+pub fn rope(steps: &[Move], knots: usize) -> Moves<'_> {
+    Moves {
+        sim: Sim::new(knots),
+        steps: steps.iter(),
+    }
+}
+
+/// The rope after a move of the head.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    /// Head first.
+    pub knots: Vec<Loc>,
+    /// Spots the tail has been on so far.
+    pub visited: usize,
+}
+
+/// See [`rope`].
+pub struct Moves<'a> {
+    sim: Sim,
+    steps: slice::Iter<'a, Move>,
+}
+
+impl Iterator for Moves<'_> {
+    type Item = Rope;
+
+    fn next(&mut self) -> Option<Rope> {
+        self.sim.step(self.steps.next()?);
+        Some(Rope {
+            knots: self.sim.rope.clone(),
+            visited: self.sim.list.len(),
+        })
+    }
+}
+
+// fn part2(input: &str) -> u32 {
+//     let tree = build_tree(input);
+
+//     let disk_usage = tree[0].size;
+
+//     let current_free = DISK_SPACE - disk_usage;
+
+//     let size_to_delete = DISK_SPACE_NEEDED - current_free;
+
+//     let dir_size: u32 = tree
+//         .iter()
+//         .filter(|e| e.item == Item::Dir && e.size >= size_to_delete)
+//         .map(|e| e.size)
+//         .min().unwrap();
+
+//     dir_size
+// }
+
+/// `size` moves of the head.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let dir = *b"UDLR".choose(rng).unwrap();
+        writeln!(out, "{} {}", char::from(dir), rng.random_range(1..=20)).unwrap();
+    }
+    out
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn part1(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part1(&parse(input)?).into())
+    }
+
+    fn part2(&self, input: &[u8]) -> Result<Answer, ParseError> {
+        Ok(part2(&parse(input)?).into())
+    }
+
+    fn animate(
+        &self,
+        part: Part,
+        input: &[u8],
+        draw: &mut dyn FnMut(&Frame),
+    ) -> Result<Answer, ParseError> {
+        let knots = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        let visits = tail_visits(&parse(input)?, knots, &mut |sim, n| {
+            draw(sim.frame().caption(format!("move {}", n + 1)));
+        });
+        Ok(visits.into())
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum EMove {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Move(EMove, i8);
+
+type Pos = i32;
+
+pub type Loc = aoc_common::Loc<Pos>;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Sim {
+    rope: Vec<Loc>,
+    list: Vec<Loc>,
+}
+impl Sim {
+    fn new(size: usize) -> Self {
+        let mut sim = Self {
+            rope: vec![Loc::default(); size],
+            list: Vec::with_capacity(100),
+        };
+        sim.list.push(Loc::default());
+        sim
+    }
+
+    fn step(&mut self, m: &Move) {
+        for _ in 0..m.1 {
+            let mut points = self.rope.iter_mut();
+
+            let mut prev = {
+                let head = points.next().unwrap();
+
+                match m.0 {
+                    EMove::Up => {
+                        head.up();
+                    }
+                    EMove::Down => {
+                        head.down();
+                    }
+                    EMove::Left => {
+                        head.left();
+                    }
+                    EMove::Right => {
+                        head.right();
+                    }
+                };
+                *head
+            };
+
+            for curr in points {
+                let diff = prev.x.abs_diff(curr.x) + prev.y.abs_diff(curr.y);
+                if diff < 2 {
+                    break;
+                }
+
+                if prev.x.abs_diff(curr.x) == 1 && prev.y.abs_diff(curr.y) == 1 {
+                    break;
+                }
+
+                let diff = prev.x - curr.x;
+                if diff < 0 {
+                    curr.left();
+                }
+                if diff > 0 {
+                    curr.right();
+                }
+
+                let diff = prev.y - curr.y;
+                if diff < 0 {
+                    curr.down();
+                }
+                if diff > 0 {
+                    curr.up();
+                }
+
+                prev = *curr;
+            }
+
+            // println!(
+            //     "h {}x{}; t {}x{} d{diff} m{m:?} H{:?}",
+            //     h.x, h.y, t.x, t.y, h
+            // );
+
+            let last = self.rope.last().unwrap();
+            if !self.list.iter().any(|v| v == last) {
+                self.list.push(*last);
+            }
+        }
+    }
+
+    /// Visited spots and the rope, up is up.
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new("");
+        for v in &self.list {
+            frame.set(v.x, -v.y, '#');
+        }
+        frame.set(0, 0, 's');
+        for (idx, v) in self.rope.iter().enumerate().rev() {
+            match idx {
+                0 => frame.paint(v.x, -v.y, 'H', 9),
+                e => frame.paint(v.x, -v.y, char::from(e as u8 + b'0'), 11),
+            };
+        }
+        let head = self.rope[0];
+        frame.focus(head.x, -head.y);
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, rope, EMove, Loc, Move, Sim};
+    use proptest::prelude::*;
+
+    const INPUT: &[u8] = include_bytes!("../input/example.txt");
+
+    #[test]
+    fn test_example() {
+        let steps = parse(INPUT).unwrap();
+
+        assert_eq!(steps.len(), 8);
+        assert_eq!(parse(b"R 4\nX 1").unwrap_err().text, "X");
+        assert_eq!(parse(b"R 4\nU 1000").unwrap_err().text, "1000");
+        assert_eq!(steps[0], Move(EMove::Right, 4));
+
+        let mut sim = Sim::new(2);
+
+        let mut step = steps.iter();
+
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 3, y: 0 });
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 4, y: 3 });
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 2, y: 4 });
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 2, y: 4 });
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 4, y: 3 });
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 4, y: 3 });
+
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 1, y: 2 });
+        let m = step.next().unwrap();
+        sim.step(m);
+        assert_eq!(sim.rope[1], Loc { x: 1, y: 2 });
+
+        assert_eq!(step.next(), None);
+        println!("{}", sim.frame());
+
+        assert_eq!(sim.list.len(), 13);
+
+        assert_eq!(part1(&steps), 13);
+        assert_eq!(part2(&steps), 1);
+    }
+
+    #[test]
+    fn test_rope() {
+        let steps = parse(INPUT).unwrap();
+        let ropes: Vec<_> = rope(&steps, 2).collect();
+        assert_eq!(ropes.len(), 8);
+        assert_eq!(ropes[0].knots, [Loc { x: 4, y: 0 }, Loc { x: 3, y: 0 }]);
+        assert_eq!(ropes[1].knots[1], Loc { x: 4, y: 3 });
+        assert_eq!(ropes.last().unwrap().visited, 13);
+        assert!(ropes.windows(2).all(|w| w[0].visited <= w[1].visited));
+    }
+
+    #[test]
+    fn test_example1() {
+        let mut sim = Sim::new(2);
+
+        assert_eq!(sim.rope[0], Loc { x: 0, y: 0 });
+        sim.step(&Move(EMove::Up, 1));
+        assert_eq!(sim.rope[0], Loc { x: 0, y: 1 });
+
+        sim.step(&Move(EMove::Right, 5));
+
+        sim.step(&Move(EMove::Down, 2));
+
+        assert_eq!(sim.rope[0], Loc { x: 5, y: -1 });
+
+        assert_eq!(Loc::default(), Loc { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_example2() {
+        let mut sim = Sim::new(10);
+
+        assert_eq!(sim.rope[0], Loc { x: 0, y: 0 });
+        sim.step(&Move(EMove::Right, 5));
+        sim.step(&Move(EMove::Up, 8));
+        println!("{}", sim.frame());
+
+        assert_eq!(*sim.rope.last().unwrap(), Loc { x: 0, y: 0 });
+
+        sim.step(&Move(EMove::Left, 8));
+        println!("{}", sim.frame());
+
+        assert_eq!(*sim.rope.last().unwrap(), Loc { x: 1, y: 3 });
+
+        sim.step(&Move(EMove::Down, 3));
+        sim.step(&Move(EMove::Right, 17));
+        println!("{}", sim.frame());
+
+        assert_eq!(*sim.rope.last().unwrap(), Loc { x: 5, y: 5 });
+    }
+
+    #[test]
+    fn test_generate() {
+        use super::generate;
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(9);
+        for size in [1, 500] {
+            let steps = parse(generate(&mut rng, size).as_bytes()).unwrap();
+            assert_eq!(steps.len(), size);
+            assert!(part2(&steps) <= part1(&steps));
+        }
+    }
+
+    fn any_move() -> impl Strategy<Value = Move> {
+        let dir = prop_oneof![
+            Just(EMove::Up),
+            Just(EMove::Down),
+            Just(EMove::Left),
+            Just(EMove::Right),
+        ];
+        (dir, 1..=20_i8).prop_map(|(dir, n)| Move(dir, n))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_knots_adjacent(
+            knots in 2..=10_usize,
+            moves in prop::collection::vec(any_move(), 0..50),
+        ) {
+            let mut sim = Sim::new(knots);
+            for m in &moves {
+                sim.step(m);
+                for w in sim.rope.windows(2) {
+                    let (a, b) = (w[0], w[1]);
+                    prop_assert!(a.x.abs_diff(b.x) <= 1 && a.y.abs_diff(b.y) <= 1);
+                }
+            }
+        }
+    }
+}
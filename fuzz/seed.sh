@@ -0,0 +1,9 @@
+#!/bin/sh
+# Starts every day's corpus with the examples the tests use.
+set -e
+cd "$(dirname "$0")"
+for example in ../day*/input/example*.txt; do
+    day=$(basename "$(dirname "$(dirname "$example")")")
+    mkdir -p "corpus/$day"
+    cp "$example" "corpus/$day/"
+done